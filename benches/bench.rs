@@ -1,3 +1,6 @@
+// the contents of the benchmarked buffers do not matter
+#![allow(clippy::uninit_vec)]
+
 extern crate crc_any;
 
 #[macro_use]
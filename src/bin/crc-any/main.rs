@@ -0,0 +1,347 @@
+//! A command-line tool to compute CRC values of files or the standard input with any built-in or custom CRC algorithm.
+
+extern crate crc_any;
+
+mod manifest;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process;
+
+use crc_any::{CRC, CRCParameters, CRCParametersBuilder};
+
+const HELP: &str = "Compute CRC values of files or the standard input.
+
+Usage: crc-any [OPTIONS] [FILE]...
+       crc-any --manifest [-a <NAME>] [PATH]...
+       crc-any --check [MANIFEST]...
+
+With no FILE (or MANIFEST), or when it is -, read the standard input.
+
+Options:
+  -a, --algorithm <NAME>  The name of a built-in algorithm in the CRC RevEng catalogue (e.g. CRC-32/ISO-HDLC), one of its aliases (e.g. PKZIP) or the name of a built-in function (e.g. crc32) [default: CRC-32/ISO-HDLC]
+      --width <BITS>      The width of a custom algorithm (1 to 128)
+      --poly <HEX>        The polynomial of a custom algorithm, not reflected and without its highest term
+      --init <HEX>        The initial value of a custom algorithm, not reflected [default: 0]
+      --xorout <HEX>      The final xor value of a custom algorithm [default: 0]
+      --reflect           Reflect the input and the output of a custom algorithm
+  -f, --format <FORMAT>   The output format: hex, dec, base64, le (raw little-endian bytes) or be (raw big-endian bytes) [default: hex]
+  -m, --manifest          Print a manifest of `algorithm value path` lines for the files in the paths, recursively [default path: .]
+  -c, --check             Verify the files listed in the manifests, and exit with a non-zero status if any of them does not match or cannot be read
+  -l, --list              List the built-in algorithms
+  -h, --help              Print this help";
+
+const DEFAULT_ALGORITHM: &str = "CRC-32/ISO-HDLC";
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+const BASE64_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Hex,
+    Decimal,
+    Base64,
+    LittleEndian,
+    BigEndian,
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    algorithm: Option<String>,
+    width: Option<u8>,
+    poly: Option<u128>,
+    init: Option<u128>,
+    xorout: Option<u128>,
+    reflect: bool,
+    format: Option<Format>,
+    manifest: bool,
+    check: bool,
+    list: bool,
+    help: bool,
+    paths: Vec<String>,
+}
+
+/// An error which makes the program exit with a non-zero status.
+enum Error {
+    /// Invalid arguments.
+    Usage(String),
+    /// Other errors, such as IO errors.
+    Other(String),
+}
+
+fn parse_hex(name: &str, value: &str) -> Result<u128, Error> {
+    let digits = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")).unwrap_or(value);
+
+    u128::from_str_radix(digits, 16).map_err(|_| Error::Usage(format!("invalid hexadecimal value for {}: {}", name, value)))
+}
+
+fn parse_format(value: &str) -> Result<Format, Error> {
+    match value.to_ascii_lowercase().as_str() {
+        "hex" => Ok(Format::Hex),
+        "dec" => Ok(Format::Decimal),
+        "base64" => Ok(Format::Base64),
+        "le" => Ok(Format::LittleEndian),
+        "be" => Ok(Format::BigEndian),
+        _ => Err(Error::Usage(format!("unknown format: {}", value))),
+    }
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Options, Error> {
+    let mut options = Options::default();
+
+    let mut only_paths = false;
+
+    while let Some(arg) = args.next() {
+        if only_paths || arg == "-" || !arg.starts_with('-') {
+            options.paths.push(arg);
+
+            continue;
+        }
+
+        if arg == "--" {
+            only_paths = true;
+
+            continue;
+        }
+
+        let (name, inline_value) = match arg.find('=') {
+            Some(index) if arg.starts_with("--") => (arg[..index].to_string(), Some(arg[index + 1..].to_string())),
+            _ => (arg.clone(), None),
+        };
+
+        let mut value = || -> Result<String, Error> {
+            match inline_value.clone() {
+                Some(value) => Ok(value),
+                None => args.next().ok_or_else(|| Error::Usage(format!("a value is required for {}", name))),
+            }
+        };
+
+        match name.as_str() {
+            "-a" | "--algorithm" => options.algorithm = Some(value()?),
+            "--width" => {
+                let width = value()?;
+
+                options.width = Some(width.parse().map_err(|_| Error::Usage(format!("invalid width: {}", width)))?);
+            },
+            "--poly" => options.poly = Some(parse_hex("--poly", &value()?)?),
+            "--init" => options.init = Some(parse_hex("--init", &value()?)?),
+            "--xorout" => options.xorout = Some(parse_hex("--xorout", &value()?)?),
+            "--reflect" => options.reflect = true,
+            "-f" | "--format" => options.format = Some(parse_format(&value()?)?),
+            "-m" | "--manifest" => options.manifest = true,
+            "-c" | "--check" => options.check = true,
+            "-l" | "--list" => options.list = true,
+            "-h" | "--help" => options.help = true,
+            _ => return Err(Error::Usage(format!("unknown option: {}", arg))),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Whether the options have any parameter of a custom algorithm.
+fn is_custom(options: &Options) -> bool {
+    options.width.is_some() || options.poly.is_some() || options.init.is_some() || options.xorout.is_some() || options.reflect
+}
+
+/// Create the CRC instance which is chosen by the options.
+fn create_crc(options: &Options) -> Result<CRC, Error> {
+    if !is_custom(options) {
+        let name = options.algorithm.as_deref().unwrap_or(DEFAULT_ALGORITHM);
+
+        return CRC::from_name(name).ok_or_else(|| Error::Usage(format!("unknown algorithm: {}", name)));
+    }
+
+    if options.algorithm.is_some() {
+        return Err(Error::Usage(String::from("--algorithm cannot be used with the parameters of a custom algorithm")));
+    }
+
+    let (width, poly) = match (options.width, options.poly) {
+        (Some(width), Some(poly)) => (width, poly),
+        _ => return Err(Error::Usage(String::from("--width and --poly are required by a custom algorithm"))),
+    };
+
+    if width == 0 || width > 128 {
+        return Err(Error::Usage(format!("invalid width: {}", width)));
+    }
+
+    let parameters = CRCParametersBuilder::new(width, poly)
+        .init(options.init.unwrap_or(0))
+        .xorout(options.xorout.unwrap_or(0))
+        .reflect(options.reflect)
+        .try_build()
+        .map_err(|err| Error::Usage(err.to_string()))?;
+
+    CRC::try_create_crc_with_parameters(&parameters).map_err(|err| Error::Usage(err.to_string()))
+}
+
+/// Digest a file, or the standard input if the path is `-`, without loading it into memory at once.
+fn digest_path(crc: &mut CRC, path: &str) -> io::Result<()> {
+    if path == "-" {
+        let stdin = io::stdin();
+
+        digest_reader(crc, &mut stdin.lock())
+    } else {
+        digest_reader(crc, &mut File::open(path)?)
+    }
+}
+
+fn digest_reader<R: Read>(crc: &mut CRC, reader: &mut R) -> io::Result<()> {
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(c) => crc.digest(&buffer[..c]),
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => (),
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn base64(data: &[u8]) -> String {
+    let mut s = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, byte)| n | ((*byte as u32) << (16 - 8 * i)));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(BASE64_TABLE[((n >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+
+    s
+}
+
+/// Format the CRC value in a text format, or return `None` for a raw format.
+fn format_text(crc: &mut CRC, format: Format) -> Option<String> {
+    match format {
+        Format::Hex => Some(crc.to_string()),
+        Format::Decimal => Some(crc.get_crc_u128().to_string()),
+        Format::Base64 => Some(base64(&crc.get_crc_vec_be())),
+        Format::LittleEndian | Format::BigEndian => None,
+    }
+}
+
+fn list() {
+    for parameters in CRCParameters::catalogue() {
+        let aliases: Vec<&str> = parameters.aliases().collect();
+
+        if aliases.is_empty() {
+            println!("{}", parameters.name);
+        } else {
+            println!("{} ({})", parameters.name, aliases.join(", "));
+        }
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let mut options = parse_args(env::args().skip(1))?;
+
+    if options.help {
+        println!("{}", HELP);
+
+        return Ok(());
+    }
+
+    if options.list {
+        list();
+
+        return Ok(());
+    }
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    if options.manifest || options.check {
+        if options.format.is_some() {
+            return Err(Error::Usage(String::from("--format cannot be used with --manifest or --check")));
+        }
+
+        if options.check {
+            if options.manifest || options.algorithm.is_some() || is_custom(&options) {
+                return Err(Error::Usage(String::from("--check only accepts the paths of manifests")));
+            }
+
+            if options.paths.is_empty() {
+                options.paths.push(String::from("-"));
+            }
+
+            return manifest::check(&options.paths, &mut stdout);
+        }
+
+        if is_custom(&options) {
+            return Err(Error::Usage(String::from("a manifest can only be created with a built-in algorithm")));
+        }
+
+        let crc = create_crc(&options)?;
+
+        // use the name in the CRC RevEng catalogue if possible
+        let name = options.algorithm.as_deref().unwrap_or(DEFAULT_ALGORITHM);
+        let name = CRCParameters::from_name(name).map(|parameters| parameters.name).unwrap_or(name);
+
+        if options.paths.is_empty() {
+            options.paths.push(String::from("."));
+        }
+
+        return manifest::create(name, &crc, &options.paths, &mut stdout);
+    }
+
+    let crc = create_crc(&options)?;
+
+    let format = options.format.unwrap_or(Format::Hex);
+
+    if options.paths.is_empty() {
+        options.paths.push(String::from("-"));
+    }
+
+    let mut result = Ok(());
+
+    for path in options.paths.iter() {
+        let mut crc = crc.clone();
+
+        if let Err(err) = digest_path(&mut crc, path) {
+            eprintln!("crc-any: {}: {}", path, err);
+
+            result = Err(Error::Other(String::new()));
+
+            continue;
+        }
+
+        let written = match format_text(&mut crc, format) {
+            Some(text) => writeln!(stdout, "{}  {}", text, path),
+            None if format == Format::LittleEndian => stdout.write_all(&crc.get_crc_vec_le()),
+            None => stdout.write_all(&crc.get_crc_vec_be()),
+        };
+
+        written.map_err(|err| Error::Other(err.to_string()))?;
+    }
+
+    stdout.flush().map_err(|err| Error::Other(err.to_string()))?;
+
+    result
+}
+
+fn main() {
+    match run() {
+        Ok(()) => (),
+        Err(Error::Usage(message)) => {
+            eprintln!("crc-any: {}\nTry 'crc-any --help' for more information.", message);
+
+            process::exit(2);
+        },
+        Err(Error::Other(message)) => {
+            if !message.is_empty() {
+                eprintln!("crc-any: {}", message);
+            }
+
+            process::exit(1);
+        },
+    }
+}
@@ -0,0 +1,186 @@
+//! Create and verify manifests of CRC values, whose lines are `algorithm value path`.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crc_any::CRC;
+
+use {digest_path, Error};
+
+/// Collect the files in a path recursively, in order of their names. Symbolic links to directories inside the path are skipped, so links which make loops are never followed. Errors are reported and the remaining entries are still collected. Return `false` if any error occurred.
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> bool {
+    let is_dir = match fs::metadata(path) {
+        Ok(metadata) => metadata.is_dir(),
+        // a broken link is reported when it is digested
+        Err(_) => false,
+    };
+
+    if !is_dir {
+        files.push(path.to_path_buf());
+
+        return true;
+    }
+
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!("crc-any: {}: {}", path.display(), err);
+
+            return false;
+        },
+    };
+
+    let mut ok = true;
+
+    let mut paths = Vec::new();
+
+    for entry in entries {
+        match entry {
+            Ok(entry) => paths.push(entry.path()),
+            Err(err) => {
+                eprintln!("crc-any: {}: {}", path.display(), err);
+
+                ok = false;
+            },
+        }
+    }
+
+    paths.sort();
+
+    for entry in paths {
+        match fs::symlink_metadata(&entry) {
+            Ok(metadata) if metadata.file_type().is_symlink() && fs::metadata(&entry).map(|metadata| metadata.is_dir()).unwrap_or(false) => {
+                eprintln!("crc-any: {}: skipping a symbolic link to a directory", entry.display());
+            },
+            Ok(_) => ok &= collect_files(&entry, files),
+            Err(err) => {
+                eprintln!("crc-any: {}: {}", entry.display(), err);
+
+                ok = false;
+            },
+        }
+    }
+
+    ok
+}
+
+/// Write a manifest of all files in the paths. The algorithm is written as `algorithm`, which must be accepted by `CRC::from_name`. Files whose paths cannot be written in a line of the manifest (i.e. paths which are not valid UTF-8 or contain line breaks) are reported as errors.
+pub fn create<W: Write>(algorithm: &str, crc: &CRC, paths: &[String], output: &mut W) -> Result<(), Error> {
+    let mut result = Ok(());
+
+    for path in paths {
+        let mut files = Vec::new();
+
+        if !collect_files(Path::new(path), &mut files) {
+            result = Err(Error::Other(String::new()));
+        }
+
+        for file in files {
+            let file = match file.to_str() {
+                Some(file) if !file.contains(['\n', '\r']) => file,
+                Some(_) => {
+                    eprintln!("crc-any: {:?}: the path contains a line break", file);
+
+                    result = Err(Error::Other(String::new()));
+
+                    continue;
+                },
+                None => {
+                    eprintln!("crc-any: {}: the path is not valid UTF-8", file.display());
+
+                    result = Err(Error::Other(String::new()));
+
+                    continue;
+                },
+            };
+
+            let mut crc = crc.clone();
+
+            if let Err(err) = digest_path(&mut crc, file) {
+                eprintln!("crc-any: {}: {}", file, err);
+
+                result = Err(Error::Other(String::new()));
+
+                continue;
+            }
+
+            writeln!(output, "{} {} {}", algorithm, crc, file).map_err(|err| Error::Other(err.to_string()))?;
+        }
+    }
+
+    result
+}
+
+/// Verify the files listed in the manifests, or the standard input if a path is `-`. Every file is reported as `OK` or `FAILED`, and the numbers of mismatches, unreadable files and improperly formatted lines are reported at the end.
+pub fn check<W: Write>(manifests: &[String], output: &mut W) -> Result<(), Error> {
+    let mut mismatched = 0;
+    let mut unreadable = 0;
+    let mut malformed = 0;
+
+    for manifest in manifests {
+        let reader: Box<dyn BufRead> = if manifest == "-" {
+            Box::new(BufReader::new(io::stdin()))
+        } else {
+            match File::open(manifest) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(err) => return Err(Error::Other(format!("{}: {}", manifest, err))),
+            }
+        };
+
+        for line in reader.lines() {
+            let line = line.map_err(|err| Error::Other(format!("{}: {}", manifest, err)))?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ' ');
+
+            let (mut crc, value, path) = match (fields.next().and_then(CRC::from_name), fields.next(), fields.next()) {
+                (Some(crc), Some(value), Some(path)) => (crc, value, path),
+                _ => {
+                    malformed += 1;
+
+                    continue;
+                },
+            };
+
+            let written = match digest_path(&mut crc, path) {
+                Ok(()) if crc.to_string().eq_ignore_ascii_case(value) => writeln!(output, "{}: OK", path),
+                Ok(()) => {
+                    mismatched += 1;
+
+                    writeln!(output, "{}: FAILED", path)
+                },
+                Err(err) => {
+                    unreadable += 1;
+
+                    eprintln!("crc-any: {}: {}", path, err);
+
+                    writeln!(output, "{}: FAILED open or read", path)
+                },
+            };
+
+            written.map_err(|err| Error::Other(err.to_string()))?;
+        }
+    }
+
+    if malformed > 0 {
+        eprintln!("crc-any: WARNING: {} line(s) are improperly formatted", malformed);
+    }
+
+    if unreadable > 0 {
+        eprintln!("crc-any: WARNING: {} listed file(s) could not be read", unreadable);
+    }
+
+    if mismatched > 0 {
+        eprintln!("crc-any: WARNING: {} computed CRC value(s) did NOT match", mismatched);
+    }
+
+    if malformed + unreadable + mismatched > 0 {
+        Err(Error::Other(String::new()))
+    } else {
+        Ok(())
+    }
+}
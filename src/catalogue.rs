@@ -0,0 +1,387 @@
+//! The catalogue of the built-in algorithms, which can be looked up by their names.
+
+#[cfg(feature = "default")]
+use alloc::vec::Vec;
+
+use {CRC, CRCParameters, Endian};
+
+/// The parameters of an algorithm along with the built-in function which creates its CRC instance.
+pub(crate) type Entry = (&'static CRCParameters, fn() -> CRC);
+
+/// The name of a built-in function along with the function.
+pub(crate) type Function = (&'static str, fn() -> CRC);
+
+/// The algorithms in the CRC RevEng catalogue, along with their built-in functions.
+pub(crate) static CATALOGUE: [Entry; 113] = [
+    (&CRCParameters::CRC_3_GSM, CRC::crc3gsm),
+    (&CRCParameters::CRC_3_ROHC, CRC::crc3rohc),
+    (&CRCParameters::CRC_4_G_704, CRC::crc4itu),
+    (&CRCParameters::CRC_4_INTERLAKEN, CRC::crc4interlaken),
+    (&CRCParameters::CRC_5_G_704, CRC::crc5itu),
+    (&CRCParameters::CRC_5_USB, CRC::crc5usb),
+    (&CRCParameters::CRC_5_EPC_C1G2, CRC::crc5epc_c1g2),
+    (&CRCParameters::CRC_6_CDMA2000_A, CRC::crc6cdma2000_a),
+    (&CRCParameters::CRC_6_CDMA2000_B, CRC::crc6cdma2000_b),
+    (&CRCParameters::CRC_6_DARC, CRC::crc6darc),
+    (&CRCParameters::CRC_6_GSM, CRC::crc6gsm),
+    (&CRCParameters::CRC_6_G_704, CRC::crc6itu),
+    (&CRCParameters::CRC_7_MMC, CRC::crc7),
+    (&CRCParameters::CRC_7_UMTS, CRC::crc7umts),
+    (&CRCParameters::CRC_7_ROHC, CRC::crc7rohc),
+    (&CRCParameters::CRC_8_SMBUS, CRC::crc8),
+    (&CRCParameters::CRC_8_CDMA2000, CRC::crc8cdma2000),
+    (&CRCParameters::CRC_8_DARC, CRC::crc8darc),
+    (&CRCParameters::CRC_8_DVB_S2, CRC::crc8dvb_s2),
+    (&CRCParameters::CRC_8_TECH_3250, CRC::crc8ebu),
+    (&CRCParameters::CRC_8_I_CODE, CRC::crc8icode),
+    (&CRCParameters::CRC_8_I_432_1, CRC::crc8itu),
+    (&CRCParameters::CRC_8_MAXIM_DOW, CRC::crc8maxim),
+    (&CRCParameters::CRC_8_ROHC, CRC::crc8rohc),
+    (&CRCParameters::CRC_8_WCDMA, CRC::crc8wcdma),
+    (&CRCParameters::CRC_8_AUTOSAR, CRC::crc8autosar),
+    (&CRCParameters::CRC_8_BLUETOOTH, CRC::crc8bluetooth),
+    (&CRCParameters::CRC_8_GSM_A, CRC::crc8gsm_a),
+    (&CRCParameters::CRC_8_GSM_B, CRC::crc8gsm_b),
+    (&CRCParameters::CRC_8_HITAG, CRC::crc8hitag),
+    (&CRCParameters::CRC_8_LTE, CRC::crc8lte),
+    (&CRCParameters::CRC_8_MIFARE_MAD, CRC::crc8mifare_mad),
+    (&CRCParameters::CRC_8_NRSC_5, CRC::crc8nrsc5),
+    (&CRCParameters::CRC_8_OPENSAFETY, CRC::crc8opensafety),
+    (&CRCParameters::CRC_8_SAE_J1850, CRC::crc8sae_j1850),
+    (&CRCParameters::CRC_10_ATM, CRC::crc10),
+    (&CRCParameters::CRC_10_CDMA2000, CRC::crc10cdma2000),
+    (&CRCParameters::CRC_10_GSM, CRC::crc10gsm),
+    (&CRCParameters::CRC_11_FLEXRAY, CRC::crc11),
+    (&CRCParameters::CRC_11_UMTS, CRC::crc11umts),
+    (&CRCParameters::CRC_12_DECT, CRC::crc12),
+    (&CRCParameters::CRC_12_UMTS, CRC::crc12umts),
+    (&CRCParameters::CRC_12_CDMA2000, CRC::crc12cdma2000),
+    (&CRCParameters::CRC_12_GSM, CRC::crc12gsm),
+    (&CRCParameters::CRC_13_BBC, CRC::crc13bbc),
+    (&CRCParameters::CRC_14_DARC, CRC::crc14darc),
+    (&CRCParameters::CRC_14_GSM, CRC::crc14gsm),
+    (&CRCParameters::CRC_15_CAN, CRC::crc15can),
+    (&CRCParameters::CRC_15_MPT1327, CRC::crc15mpt1327),
+    (&CRCParameters::CRC_16_ARC, CRC::crc16),
+    (&CRCParameters::CRC_16_IBM_3740, CRC::crc16ccitt_false),
+    (&CRCParameters::CRC_16_SPI_FUJITSU, CRC::crc16aug_ccitt),
+    (&CRCParameters::CRC_16_UMTS, CRC::crc16buypass),
+    (&CRCParameters::CRC_16_CDMA2000, CRC::crc16cdma2000),
+    (&CRCParameters::CRC_16_DDS_110, CRC::crc16dds_110),
+    (&CRCParameters::CRC_16_DECT_R, CRC::crc16dect_r),
+    (&CRCParameters::CRC_16_DECT_X, CRC::crc16dect_x),
+    (&CRCParameters::CRC_16_DNP, CRC::crc16dnp),
+    (&CRCParameters::CRC_16_EN_13757, CRC::crc16en_13757),
+    (&CRCParameters::CRC_16_GENIBUS, CRC::crc16genibus),
+    (&CRCParameters::CRC_16_MAXIM_DOW, CRC::crc16maxim),
+    (&CRCParameters::CRC_16_MCRF4XX, CRC::crc16mcrf4cc),
+    (&CRCParameters::CRC_16_RIELLO, CRC::crc16riello),
+    (&CRCParameters::CRC_16_T10_DIF, CRC::crc16t10_dif),
+    (&CRCParameters::CRC_16_TELEDISK, CRC::crc16teledisk),
+    (&CRCParameters::CRC_16_TMS37157, CRC::crc16tms13157),
+    (&CRCParameters::CRC_16_USB, CRC::crc16usb),
+    (&CRCParameters::CRC_16_ISO_IEC_14443_3_A, CRC::crc_a),
+    (&CRCParameters::CRC_16_KERMIT, CRC::crc16kermit),
+    (&CRCParameters::CRC_16_MODBUS, CRC::crc16modbus),
+    (&CRCParameters::CRC_16_IBM_SDLC, CRC::crc16_x25),
+    (&CRCParameters::CRC_16_XMODEM, CRC::crc16xmodem),
+    (&CRCParameters::CRC_16_CMS, CRC::crc16cms),
+    (&CRCParameters::CRC_16_GSM, CRC::crc16gsm),
+    (&CRCParameters::CRC_16_LJ1200, CRC::crc16lj1200),
+    (&CRCParameters::CRC_16_M17, CRC::crc16m17),
+    (&CRCParameters::CRC_16_NRSC_5, CRC::crc16nrsc5),
+    (&CRCParameters::CRC_16_OPENSAFETY_A, CRC::crc16opensafety_a),
+    (&CRCParameters::CRC_16_OPENSAFETY_B, CRC::crc16opensafety_b),
+    (&CRCParameters::CRC_16_PROFIBUS, CRC::crc16profibus),
+    (&CRCParameters::CRC_17_CAN_FD, CRC::crc17can),
+    (&CRCParameters::CRC_21_CAN_FD, CRC::crc21can),
+    (&CRCParameters::CRC_24_OPENPGP, CRC::crc24),
+    (&CRCParameters::CRC_24_BLE, CRC::crc24ble),
+    (&CRCParameters::CRC_24_FLEXRAY_A, CRC::crc24flexray_a),
+    (&CRCParameters::CRC_24_FLEXRAY_B, CRC::crc24flexray_b),
+    (&CRCParameters::CRC_24_LTE_A, CRC::crc24lte_a),
+    (&CRCParameters::CRC_24_LTE_B, CRC::crc24lte_b),
+    (&CRCParameters::CRC_24_OS_9, CRC::crc24os9),
+    (&CRCParameters::CRC_24_INTERLAKEN, CRC::crc24interlaken),
+    (&CRCParameters::CRC_30_CDMA, CRC::crc30cdma),
+    (&CRCParameters::CRC_31_PHILIPS, CRC::crc31philips),
+    (&CRCParameters::CRC_32_ISO_HDLC, CRC::crc32),
+    (&CRCParameters::CRC_32_BZIP2, CRC::crc32bzip2),
+    (&CRCParameters::CRC_32_ISCSI, CRC::crc32c),
+    (&CRCParameters::CRC_32_BASE91_D, CRC::crc32d),
+    (&CRCParameters::CRC_32_MPEG_2, CRC::crc32mpeg2),
+    (&CRCParameters::CRC_32_CKSUM, CRC::crc32posix),
+    (&CRCParameters::CRC_32_AIXM, CRC::crc32q),
+    (&CRCParameters::CRC_32_JAMCRC, CRC::crc32jamcrc),
+    (&CRCParameters::CRC_32_XFER, CRC::crc32xfer),
+    (&CRCParameters::CRC_32_AUTOSAR, CRC::crc32autosar),
+    (&CRCParameters::CRC_32_CD_ROM_EDC, CRC::crc32cd_rom_edc),
+    (&CRCParameters::CRC_32_MEF, CRC::crc32mef),
+    (&CRCParameters::CRC_40_GSM, CRC::crc40gsm),
+    (&CRCParameters::CRC_64_WE, CRC::crc64),
+    (&CRCParameters::CRC_64_GO_ISO, CRC::crc64iso),
+    (&CRCParameters::CRC_64_REDIS, CRC::crc64jones),
+    (&CRCParameters::CRC_64_ECMA_182, CRC::crc64ecma182),
+    (&CRCParameters::CRC_64_MS, CRC::crc64ms),
+    (&CRCParameters::CRC_64_NVME, CRC::crc64nvme),
+    (&CRCParameters::CRC_64_XZ, CRC::crc64xz),
+    (&CRCParameters::CRC_82_DARC, CRC::crc82darc),
+];
+
+/// The aliases of the algorithms in the CRC RevEng catalogue, along with their names.
+pub(crate) static ALIASES: [(&str, &str); 74] = [
+    ("CRC-4/ITU", "CRC-4/G-704"),
+    ("CRC-5/EPC", "CRC-5/EPC-C1G2"),
+    ("CRC-5/ITU", "CRC-5/G-704"),
+    ("CRC-6/ITU", "CRC-6/G-704"),
+    ("CRC-7", "CRC-7/MMC"),
+    ("CRC-8/ITU", "CRC-8/I-432-1"),
+    ("CRC-8/MAXIM", "CRC-8/MAXIM-DOW"),
+    ("DOW-CRC", "CRC-8/MAXIM-DOW"),
+    ("CRC-8", "CRC-8/SMBUS"),
+    ("CRC-8/AES", "CRC-8/TECH-3250"),
+    ("CRC-8/EBU", "CRC-8/TECH-3250"),
+    ("CRC-10", "CRC-10/ATM"),
+    ("CRC-10/I-610", "CRC-10/ATM"),
+    ("CRC-11", "CRC-11/FLEXRAY"),
+    ("X-CRC-12", "CRC-12/DECT"),
+    ("CRC-12/3GPP", "CRC-12/UMTS"),
+    ("CRC-15", "CRC-15/CAN"),
+    ("ARC", "CRC-16/ARC"),
+    ("CRC-16", "CRC-16/ARC"),
+    ("CRC-16/LHA", "CRC-16/ARC"),
+    ("CRC-IBM", "CRC-16/ARC"),
+    ("R-CRC-16", "CRC-16/DECT-R"),
+    ("X-CRC-16", "CRC-16/DECT-X"),
+    ("CRC-16/DARC", "CRC-16/GENIBUS"),
+    ("CRC-16/EPC", "CRC-16/GENIBUS"),
+    ("CRC-16/EPC-C1G2", "CRC-16/GENIBUS"),
+    ("CRC-16/I-CODE", "CRC-16/GENIBUS"),
+    ("CRC-16/AUTOSAR", "CRC-16/IBM-3740"),
+    ("CRC-16/CCITT-FALSE", "CRC-16/IBM-3740"),
+    ("CRC-16/ISO-HDLC", "CRC-16/IBM-SDLC"),
+    ("CRC-16/ISO-IEC-14443-3-B", "CRC-16/IBM-SDLC"),
+    ("CRC-16/X-25", "CRC-16/IBM-SDLC"),
+    ("CRC-B", "CRC-16/IBM-SDLC"),
+    ("X-25", "CRC-16/IBM-SDLC"),
+    ("CRC-A", "CRC-16/ISO-IEC-14443-3-A"),
+    ("CRC-16/BLUETOOTH", "CRC-16/KERMIT"),
+    ("CRC-16/CCITT", "CRC-16/KERMIT"),
+    ("CRC-16/CCITT-TRUE", "CRC-16/KERMIT"),
+    ("CRC-16/V-41-LSB", "CRC-16/KERMIT"),
+    ("CRC-CCITT", "CRC-16/KERMIT"),
+    ("KERMIT", "CRC-16/KERMIT"),
+    ("CRC-16/MAXIM", "CRC-16/MAXIM-DOW"),
+    ("MODBUS", "CRC-16/MODBUS"),
+    ("CRC-16/IEC-61158-2", "CRC-16/PROFIBUS"),
+    ("CRC-16/AUG-CCITT", "CRC-16/SPI-FUJITSU"),
+    ("CRC-16/BUYPASS", "CRC-16/UMTS"),
+    ("CRC-16/VERIFONE", "CRC-16/UMTS"),
+    ("CRC-16/ACORN", "CRC-16/XMODEM"),
+    ("CRC-16/LTE", "CRC-16/XMODEM"),
+    ("CRC-16/V-41-MSB", "CRC-16/XMODEM"),
+    ("XMODEM", "CRC-16/XMODEM"),
+    ("ZMODEM", "CRC-16/XMODEM"),
+    ("CRC-24", "CRC-24/OPENPGP"),
+    ("CRC-32Q", "CRC-32/AIXM"),
+    ("CRC-32D", "CRC-32/BASE91-D"),
+    ("CRC-32/AAL5", "CRC-32/BZIP2"),
+    ("CRC-32/DECT-B", "CRC-32/BZIP2"),
+    ("B-CRC-32", "CRC-32/BZIP2"),
+    ("CKSUM", "CRC-32/CKSUM"),
+    ("CRC-32/POSIX", "CRC-32/CKSUM"),
+    ("CRC-32/BASE91-C", "CRC-32/ISCSI"),
+    ("CRC-32/CASTAGNOLI", "CRC-32/ISCSI"),
+    ("CRC-32/INTERLAKEN", "CRC-32/ISCSI"),
+    ("CRC-32C", "CRC-32/ISCSI"),
+    ("CRC-32/NVME", "CRC-32/ISCSI"),
+    ("CRC-32", "CRC-32/ISO-HDLC"),
+    ("CRC-32/ADCCP", "CRC-32/ISO-HDLC"),
+    ("CRC-32/V-42", "CRC-32/ISO-HDLC"),
+    ("CRC-32/XZ", "CRC-32/ISO-HDLC"),
+    ("PKZIP", "CRC-32/ISO-HDLC"),
+    ("JAMCRC", "CRC-32/JAMCRC"),
+    ("XFER", "CRC-32/XFER"),
+    ("CRC-64", "CRC-64/ECMA-182"),
+    ("CRC-64/GO-ECMA", "CRC-64/XZ"),
+];
+
+/// The names of the built-in functions, which can also be used as the names of their algorithms.
+pub(crate) static FUNCTIONS: [Function; 120] = [
+    ("crc3gsm", CRC::crc3gsm),
+    ("crc3rohc", CRC::crc3rohc),
+    ("crc4itu", CRC::crc4itu),
+    ("crc4interlaken", CRC::crc4interlaken),
+    ("crc5epc", CRC::crc5epc),
+    ("crc5itu", CRC::crc5itu),
+    ("crc5usb", CRC::crc5usb),
+    ("crc5epc_c1g2", CRC::crc5epc_c1g2),
+    ("crc6cdma2000_a", CRC::crc6cdma2000_a),
+    ("crc6cdma2000_b", CRC::crc6cdma2000_b),
+    ("crc6darc", CRC::crc6darc),
+    ("crc6gsm", CRC::crc6gsm),
+    ("crc6itu", CRC::crc6itu),
+    ("crc7", CRC::crc7),
+    ("crc7umts", CRC::crc7umts),
+    ("crc7rohc", CRC::crc7rohc),
+    ("crc8", CRC::crc8),
+    ("crc8cdma2000", CRC::crc8cdma2000),
+    ("crc8darc", CRC::crc8darc),
+    ("crc8dvb_s2", CRC::crc8dvb_s2),
+    ("crc8ebu", CRC::crc8ebu),
+    ("crc8icode", CRC::crc8icode),
+    ("crc8itu", CRC::crc8itu),
+    ("crc8maxim", CRC::crc8maxim),
+    ("crc8rohc", CRC::crc8rohc),
+    ("crc8wcdma", CRC::crc8wcdma),
+    ("crc8autosar", CRC::crc8autosar),
+    ("crc8bluetooth", CRC::crc8bluetooth),
+    ("crc8gsm_a", CRC::crc8gsm_a),
+    ("crc8gsm_b", CRC::crc8gsm_b),
+    ("crc8hitag", CRC::crc8hitag),
+    ("crc8lte", CRC::crc8lte),
+    ("crc8mifare_mad", CRC::crc8mifare_mad),
+    ("crc8nrsc5", CRC::crc8nrsc5),
+    ("crc8opensafety", CRC::crc8opensafety),
+    ("crc8sae_j1850", CRC::crc8sae_j1850),
+    ("crc10", CRC::crc10),
+    ("crc10cdma2000", CRC::crc10cdma2000),
+    ("crc10gsm", CRC::crc10gsm),
+    ("crc11", CRC::crc11),
+    ("crc11umts", CRC::crc11umts),
+    ("crc12", CRC::crc12),
+    ("crc12umts", CRC::crc12umts),
+    ("crc12cdma2000", CRC::crc12cdma2000),
+    ("crc12gsm", CRC::crc12gsm),
+    ("crc13bbc", CRC::crc13bbc),
+    ("crc14darc", CRC::crc14darc),
+    ("crc14gsm", CRC::crc14gsm),
+    ("crc15can", CRC::crc15can),
+    ("crc15mpt1327", CRC::crc15mpt1327),
+    ("crc16", CRC::crc16),
+    ("crc16ccitt_false", CRC::crc16ccitt_false),
+    ("crc16aug_ccitt", CRC::crc16aug_ccitt),
+    ("crc16buypass", CRC::crc16buypass),
+    ("crc16cdma2000", CRC::crc16cdma2000),
+    ("crc16dds_110", CRC::crc16dds_110),
+    ("crc16dect_r", CRC::crc16dect_r),
+    ("crc16dect_x", CRC::crc16dect_x),
+    ("crc16dnp", CRC::crc16dnp),
+    ("crc16en_13757", CRC::crc16en_13757),
+    ("crc16genibus", CRC::crc16genibus),
+    ("crc16maxim", CRC::crc16maxim),
+    ("crc16mcrf4cc", CRC::crc16mcrf4cc),
+    ("crc16riello", CRC::crc16riello),
+    ("crc16t10_dif", CRC::crc16t10_dif),
+    ("crc16teledisk", CRC::crc16teledisk),
+    ("crc16tms13157", CRC::crc16tms13157),
+    ("crc16usb", CRC::crc16usb),
+    ("crc_a", CRC::crc_a),
+    ("crc16kermit", CRC::crc16kermit),
+    ("crc16modbus", CRC::crc16modbus),
+    ("crc16_x25", CRC::crc16_x25),
+    ("crc16ibm_sdlc", CRC::crc16ibm_sdlc),
+    ("crc16xmodem", CRC::crc16xmodem),
+    ("crc16cms", CRC::crc16cms),
+    ("crc16gsm", CRC::crc16gsm),
+    ("crc16lj1200", CRC::crc16lj1200),
+    ("crc16m17", CRC::crc16m17),
+    ("crc16nrsc5", CRC::crc16nrsc5),
+    ("crc16opensafety_a", CRC::crc16opensafety_a),
+    ("crc16opensafety_b", CRC::crc16opensafety_b),
+    ("crc16profibus", CRC::crc16profibus),
+    ("crc17can", CRC::crc17can),
+    ("crc21can", CRC::crc21can),
+    ("crc24", CRC::crc24),
+    ("crc24ble", CRC::crc24ble),
+    ("crc24flexray_a", CRC::crc24flexray_a),
+    ("crc24flexray_b", CRC::crc24flexray_b),
+    ("crc24lte_a", CRC::crc24lte_a),
+    ("crc24lte_b", CRC::crc24lte_b),
+    ("crc24os9", CRC::crc24os9),
+    ("crc24interlaken", CRC::crc24interlaken),
+    ("crc30cdma", CRC::crc30cdma),
+    ("crc31philips", CRC::crc31philips),
+    ("crc32", CRC::crc32),
+    ("crc32mhash", CRC::crc32mhash),
+    ("crc32bzip2", CRC::crc32bzip2),
+    ("crc32c", CRC::crc32c),
+    ("crc32iscsi", CRC::crc32iscsi),
+    ("crc32d", CRC::crc32d),
+    ("crc32mpeg2", CRC::crc32mpeg2),
+    ("crc32posix", CRC::crc32posix),
+    ("crc32q", CRC::crc32q),
+    ("crc32jamcrc", CRC::crc32jamcrc),
+    ("crc32xfer", CRC::crc32xfer),
+    ("crc32autosar", CRC::crc32autosar),
+    ("crc32cd_rom_edc", CRC::crc32cd_rom_edc),
+    ("crc32mef", CRC::crc32mef),
+    ("crc40gsm", CRC::crc40gsm),
+    ("crc64", CRC::crc64),
+    ("crc64we", CRC::crc64we),
+    ("crc64iso", CRC::crc64iso),
+    ("crc64go_iso", CRC::crc64go_iso),
+    ("crc64jones", CRC::crc64jones),
+    ("crc64redis", CRC::crc64redis),
+    ("crc64ecma182", CRC::crc64ecma182),
+    ("crc64ms", CRC::crc64ms),
+    ("crc64nvme", CRC::crc64nvme),
+    ("crc64xz", CRC::crc64xz),
+    ("crc82darc", CRC::crc82darc),
+];
+
+/// Find an algorithm in the CRC RevEng catalogue by its name or one of its aliases, ignoring ASCII case.
+pub(crate) fn find(name: &str) -> Option<&'static Entry> {
+    let name = ALIASES.iter().find(|(alias, _)| alias.eq_ignore_ascii_case(name)).map(|(_, name)| *name).unwrap_or(name);
+
+    CATALOGUE.iter().find(|(parameters, _)| parameters.name.eq_ignore_ascii_case(name))
+}
+
+/// A built-in algorithm which matches some samples, found by `CRC::identify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CRCMatch {
+    /// The parameters of the algorithm in the CRC RevEng catalogue.
+    pub parameters: &'static CRCParameters,
+    /// The byte order of the CRC values, supposing that the expected CRC values were read from bytes as big-endian integers, so `Endian::Little` means that the expected CRC values are byte-swapped. It is always `Endian::Big` for a CRC of 8 bits or less, or if the CRC values read the same in both byte orders.
+    pub endian: Endian,
+}
+
+/// Find the algorithms in the CRC RevEng catalogue which produce the given CRC values (read in either byte order) of the messages.
+#[cfg(feature = "default")]
+pub(crate) fn identify(samples: &[(&[u8], u128)]) -> Vec<CRCMatch> {
+    let mut matches = Vec::new();
+
+    if samples.is_empty() {
+        return matches;
+    }
+
+    for (parameters, create) in CATALOGUE.iter() {
+        let bytes = (parameters.width as u32).div_ceil(8);
+
+        let values: Vec<u128> = samples
+            .iter()
+            .map(|(message, _)| {
+                let mut crc = create();
+
+                crc.digest(message);
+
+                crc.get_crc_u128()
+            })
+            .collect();
+
+        // if all values read the same in both byte orders, the byte order cannot be told, so the algorithm is only matched once as big-endian
+        if samples.iter().zip(values.iter()).all(|((_, expected), value)| expected == value) {
+            matches.push(CRCMatch {
+                parameters,
+                endian: Endian::Big,
+            });
+        } else if bytes > 1 && samples.iter().zip(values.iter()).all(|((_, expected), value)| *expected == value.swap_bytes() >> (128 - bytes * 8)) {
+            matches.push(CRCMatch {
+                parameters,
+                endian: Endian::Little,
+            });
+        }
+    }
+
+    matches
+}
@@ -0,0 +1,228 @@
+//! Correct single-bit and double-bit errors of frames (messages followed by their CRC values) with the syndromes of CRCs.
+//!
+//! A CRC is linear, so the syndrome of a received frame, which is the computed CRC value of the message xored with the received CRC value, only depends on the flipped bits. The syndrome of every single-bit error of a frame of a fixed length is computed in advance and looked up when a frame is corrected.
+
+use core::fmt::{self, Display, Formatter};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use {CRC, CRCError, CRCParameters, Endian};
+
+/// The reasons why a frame cannot be corrected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorrectionError {
+    /// The length of the frame (the first value) is not the length which the corrector was created for (the second value).
+    InvalidLength(usize, usize),
+    /// The frame has more erroneous bits than the corrector can correct, or their positions cannot be told apart.
+    Uncorrectable,
+}
+
+impl Display for CorrectionError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CorrectionError::InvalidLength(length, expected) => f.write_fmt(format_args!("the length of the frame {} is not {}", length, expected)),
+            CorrectionError::Uncorrectable => f.write_str("the frame cannot be corrected"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CorrectionError {}
+
+/// The bits which were flipped to correct a frame. A bit is located by `byte_index * 8 + bit`, where the bit `0` is the least significant bit of a byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Correction {
+    /// The frame has no error.
+    None,
+    /// One bit was flipped.
+    Single(usize),
+    /// Two bits were flipped.
+    Double(usize, usize),
+}
+
+/// A corrector of the frames of a CRC algorithm, whose messages have a fixed length and are followed by their CRC values in the given byte order.
+///
+/// Errors of more bits than the corrector can correct may have the same syndromes as some correctable errors, and then they are miscorrected, so the length of the messages should be short enough for the Hamming distance of the CRC.
+#[derive(Debug, Clone)]
+pub struct CRCCorrector {
+    crc: CRC,
+    length: usize,
+    endian: Endian,
+    double_bit: bool,
+    /// The syndrome of every single-bit error, indexed by the location of the bit.
+    syndromes: Vec<u128>,
+    /// `(syndrome, location)` pairs sorted by their syndromes.
+    table: Vec<(u128, usize)>,
+}
+
+impl CRCCorrector {
+    /// Create a `CRCCorrector` instance by providing the parameters of a CRC algorithm, the length of the messages in bytes and the byte order of the CRC values. Only single-bit errors are corrected unless `double_bit` is enabled.
+    pub fn new(parameters: &CRCParameters, length: usize, endian: Endian) -> Result<CRCCorrector, CRCError> {
+        let crc = CRC::try_create_crc_with_parameters(parameters)?;
+
+        let width = parameters.width;
+        let e = (width as usize).div_ceil(8);
+        let mask = u128::MAX >> (128 - width);
+        let top = 1u128 << (width - 1);
+
+        let multiply_x = |register: u128| {
+            let carry = register & top != 0;
+
+            let register = (register << 1) & mask;
+
+            if carry {
+                register ^ parameters.poly
+            } else {
+                register
+            }
+        };
+
+        let mut syndromes = vec![0u128; (length + e) * 8];
+
+        // a bit of the message which is followed by m bits contributes x^(m + w) mod P
+        let mut register = parameters.poly;
+
+        for m in 0..length * 8 {
+            let p = length * 8 - 1 - m;
+
+            let bit = if parameters.refin {
+                p % 8
+            } else {
+                7 - p % 8
+            };
+
+            syndromes[p / 8 * 8 + bit] = if parameters.refout {
+                register.reverse_bits() >> (128 - width)
+            } else {
+                register
+            };
+
+            register = multiply_x(register);
+        }
+
+        // a bit of the CRC value contributes itself
+        for k in 0..e {
+            let byte = match endian {
+                Endian::Big => e - 1 - k,
+                Endian::Little => k,
+            };
+
+            for bit in 0..8 {
+                syndromes[(length + k) * 8 + bit] = 1 << (byte * 8 + bit);
+            }
+        }
+
+        let mut table: Vec<(u128, usize)> = syndromes.iter().enumerate().map(|(location, syndrome)| (*syndrome, location)).collect();
+
+        table.sort_unstable();
+
+        Ok(CRCCorrector {
+            crc,
+            length,
+            endian,
+            double_bit: false,
+            syndromes,
+            table,
+        })
+    }
+
+    /// Set whether double-bit errors are also corrected. It is disabled by default.
+    #[inline]
+    pub fn double_bit(mut self, double_bit: bool) -> CRCCorrector {
+        self.double_bit = double_bit;
+
+        self
+    }
+
+    /// Get the length of the frames in bytes, which is the length of the messages plus the length of the CRC values.
+    #[inline]
+    pub fn frame_length(&self) -> usize {
+        self.syndromes.len() / 8
+    }
+
+    /// Compute the syndrome of a frame. It is zero if the frame has no error.
+    pub fn syndrome(&self, frame: &[u8]) -> Result<u128, CorrectionError> {
+        if frame.len() != self.frame_length() {
+            return Err(CorrectionError::InvalidLength(frame.len(), self.frame_length()));
+        }
+
+        let (message, received) = frame.split_at(self.length);
+
+        let mut crc = self.crc.clone();
+
+        crc.digest(message);
+
+        let computed = crc.get_crc_u128();
+
+        let received = match self.endian {
+            Endian::Big => received.iter().fold(0u128, |n, byte| (n << 8) | *byte as u128),
+            Endian::Little => received.iter().rev().fold(0u128, |n, byte| (n << 8) | *byte as u128),
+        };
+
+        Ok(computed ^ received)
+    }
+
+    /// The locations of the single-bit errors whose syndromes are the given one.
+    #[inline]
+    fn lookup(&self, syndrome: u128) -> &[(u128, usize)] {
+        let start = self.table.partition_point(|(s, _)| *s < syndrome);
+        let end = self.table.partition_point(|(s, _)| *s <= syndrome);
+
+        &self.table[start..end]
+    }
+
+    /// Locate the erroneous bits of a frame without changing it.
+    pub fn locate(&self, frame: &[u8]) -> Result<Correction, CorrectionError> {
+        let syndrome = self.syndrome(frame)?;
+
+        if syndrome == 0 {
+            return Ok(Correction::None);
+        }
+
+        match self.lookup(syndrome) {
+            [(_, location)] => return Ok(Correction::Single(*location)),
+            [] => (),
+            _ => return Err(CorrectionError::Uncorrectable),
+        }
+
+        if !self.double_bit {
+            return Err(CorrectionError::Uncorrectable);
+        }
+
+        let mut correction = None;
+
+        for (i, s) in self.syndromes.iter().enumerate() {
+            for (_, j) in self.lookup(syndrome ^ s) {
+                if *j > i {
+                    if correction.is_some() {
+                        return Err(CorrectionError::Uncorrectable);
+                    }
+
+                    correction = Some(Correction::Double(i, *j));
+                }
+            }
+        }
+
+        correction.ok_or(CorrectionError::Uncorrectable)
+    }
+
+    /// Correct a frame in place by flipping its erroneous bits, and return the locations of them. The frame is not changed if it cannot be corrected.
+    pub fn correct(&self, frame: &mut [u8]) -> Result<Correction, CorrectionError> {
+        let correction = self.locate(frame)?;
+
+        match correction {
+            Correction::None => (),
+            Correction::Single(i) => frame[i / 8] ^= 1 << (i % 8),
+            Correction::Double(i, j) => {
+                frame[i / 8] ^= 1 << (i % 8);
+                frame[j / 8] ^= 1 << (j % 8);
+            },
+        }
+
+        Ok(correction)
+    }
+}
@@ -0,0 +1,696 @@
+#[cfg(feature = "default")]
+use alloc::vec::Vec;
+#[cfg(feature = "default")]
+use alloc::fmt::{self, Formatter, Display, Debug};
+
+use core::hash::Hasher;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use parameters::{residue, CRCParameters, CRCParametersBuilder};
+use error::{CRCError, check_parameters};
+use table::Table;
+use endian::Endian;
+
+/// This struct can help you compute a CRC-128 (or CRC-x where **x** is under `128`) value.
+#[derive(Clone)]
+pub struct CRCu128 {
+    poly: u128,
+    lookup_table: Table<[u128; 256]>,
+    sum: u128,
+    pub(crate) bits: u8,
+    mask: u128,
+    initial: u128,
+    final_xor: u128,
+    reflect: bool,
+    reflect_out: bool,
+}
+
+#[cfg(feature = "default")]
+impl Debug for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        impl_debug_for_struct!(CRCu128, f, self, (.poly, "0x{:032X}", self.poly), let .lookup_table = &self.lookup_table[..], (.sum, "0x{:032X}", self.sum), .bits, (.initial, "0x{:032X}", self.initial), (.final_xor, "0x{:032X}", self.final_xor), .reflect, .reflect_out);
+    }
+}
+
+#[cfg(feature = "default")]
+impl Display for CRCu128 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_fmt(format_args!("0x{:01$X}", self.get_crc(), ((self.bits as f64 + 3f64) / 4f64) as usize))
+    }
+}
+
+/// The `finish` method returns the lowest 64 bits of the CRC value.
+impl Hasher for CRCu128 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.get_crc() as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.digest(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Write for CRCu128 {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.digest(buf);
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl CRCu128 {
+    /// Create a `CRCu128` instance by providing the length of bits, expression, reflection, an initial value and a final xor value. Use `create_crc_with_lookup_table` in a const context.
+    pub fn create_crc(poly: u128, bits: u8, initial: u128, final_xor: u128, reflect: bool) -> CRCu128 {
+        debug_assert!(bits <= 128 && bits > 0);
+
+        let lookup_table = if reflect {
+            Self::crc_reflect_table(poly)
+        } else {
+            Self::crc_table(poly, bits)
+        };
+
+        Self::create_crc_with_table(Table::new(lookup_table), bits, initial, final_xor, reflect)
+    }
+
+    /// Create a `CRCu128` instance by providing the length of bits, expression, reflection of the input and the output, an initial value and a final xor value. The expression is reflected if `reflect_in` is `true`, like the one which `create_crc` accepts.
+    #[inline]
+    pub fn create_crc_with_reflection(poly: u128, bits: u8, initial: u128, final_xor: u128, reflect_in: bool, reflect_out: bool) -> CRCu128 {
+        let mut crc = Self::create_crc(poly, bits, initial, final_xor, reflect_in);
+
+        crc.reflect_out = reflect_out;
+
+        crc
+    }
+
+    /// Create a `CRCu128` instance by providing the parameters of an algorithm.
+    #[inline]
+    pub fn create_crc_with_parameters(parameters: &CRCParameters) -> CRCu128 {
+        debug_assert!(parameters.width <= 128 && parameters.width > 0);
+
+        let poly = parameters.poly;
+
+        let poly = if parameters.refin {
+            Self::reflect_function(1 << (parameters.width - 1), poly)
+        } else {
+            poly
+        };
+
+        Self::create_crc_with_reflection(poly, parameters.width, parameters.init, parameters.xorout, parameters.refin, parameters.refout)
+    }
+
+    /// Create a `CRCu128` instance like `create_crc`, but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc(poly: u128, bits: u8, initial: u128, final_xor: u128, reflect: bool) -> Result<CRCu128, CRCError> {
+        check_parameters(poly, bits, 128, initial, final_xor, reflect)?;
+
+        Ok(Self::create_crc(poly, bits, initial, final_xor, reflect))
+    }
+
+    /// Create a `CRCu128` instance like `create_crc_with_reflection`, but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc_with_reflection(poly: u128, bits: u8, initial: u128, final_xor: u128, reflect_in: bool, reflect_out: bool) -> Result<CRCu128, CRCError> {
+        check_parameters(poly, bits, 128, initial, final_xor, reflect_in)?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect_in, reflect_out))
+    }
+
+    /// Create a `CRCu128` instance like `create_crc_with_parameters`, but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc_with_parameters(parameters: &CRCParameters) -> Result<CRCu128, CRCError> {
+        check_parameters(parameters.poly, parameters.width, 128, parameters.init, parameters.xorout, false)?;
+
+        Ok(Self::create_crc_with_parameters(parameters))
+    }
+
+    /// Create a `CRCu128` instance by providing a static lookup table, the length of bits, reflection, an initial value and a final xor value. The lookup table can be generated by the `crc_table` (or `crc_reflect_table`) function at compile time, so this function can be used to create a `static` instance.
+    #[inline]
+    pub const fn create_crc_with_lookup_table(lookup_table: &'static [u128; 256], bits: u8, initial: u128, final_xor: u128, reflect: bool) -> CRCu128 {
+        debug_assert!(bits <= 128 && bits > 0);
+
+        Self::create_crc_with_table(Table::Static(lookup_table), bits, initial, final_xor, reflect)
+    }
+
+    const fn create_crc_with_table(lookup_table: Table<[u128; 256]>, bits: u8, initial: u128, final_xor: u128, reflect: bool) -> CRCu128 {
+        let high_bit = 1 << (bits - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+
+        // the polynomial of a lookup table can be found at index 1 (or index 128 for a reflected one)
+        let poly = if reflect {
+            lookup_table.get()[0x80]
+        } else {
+            lookup_table.get()[1]
+        };
+
+        let sum = if reflect {
+            Self::reflect_function(high_bit, initial)
+        } else {
+            initial
+        };
+
+        CRCu128 {
+            poly,
+            lookup_table,
+            sum,
+            bits,
+            mask,
+            initial,
+            final_xor,
+            reflect,
+            reflect_out: reflect,
+        }
+    }
+
+    #[inline]
+    const fn reflect_function(high_bit: u128, n: u128) -> u128 {
+        let mut i = high_bit;
+        let mut j = 1;
+        let mut out = 0;
+
+        while i != 0 {
+            if n & i != 0 {
+                out |= j;
+            }
+
+            j <<= 1;
+            i >>= 1;
+        }
+
+        out
+    }
+
+    /// Reflect a register value if the output is not reflected in the same way as the input (or the other way around, as the reflection is its own inverse).
+    #[inline]
+    const fn reflect_output(&self, sum: u128) -> u128 {
+        if self.reflect == self.reflect_out {
+            sum
+        } else {
+            Self::reflect_function(1 << (self.bits - 1), sum)
+        }
+    }
+
+    /// Digest some data. Use `digest_slice` in a const context.
+    #[inline]
+    pub fn digest<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
+        self.digest_slice(data.as_ref());
+    }
+
+    /// Digest some data by using multiple threads. Large data is split into chunks whose CRC values are computed in parallel and then combined, so the result is the same as the one of the `digest` method.
+    #[cfg(feature = "rayon")]
+    pub fn digest_parallel<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
+        let data = data.as_ref();
+
+        if data.len() < super::parallel::MIN_LENGTH {
+            self.digest(data);
+
+            return;
+        }
+
+        let crc_b = super::parallel::digest(
+            data,
+            |chunk| {
+                let mut crc = self.clone();
+
+                crc.reset();
+                crc.digest(chunk);
+
+                crc.get_crc()
+            },
+            |crc_a, crc_b, len_b| self.combine(crc_a, crc_b, len_b),
+        );
+
+        let crc = self.combine(self.get_crc(), crc_b, data.len() as u64);
+
+        self.sum = self.reflect_output((crc ^ self.final_xor) & self.mask);
+    }
+
+    /// Digest the first `bit_len` bits of some data, taking the bits of each byte from the most significant one (MSB-first). For a non-reflected CRC, digesting whole bytes in this way is the same as calling the `digest` method.
+    pub fn digest_bits(&mut self, data: &[u8], bit_len: usize) {
+        self.digest_bits_in_order(data, bit_len, true);
+    }
+
+    /// Digest the first `bit_len` bits of some data, taking the bits of each byte from the least significant one (LSB-first). For a reflected CRC, digesting whole bytes in this way is the same as calling the `digest` method.
+    pub fn digest_bits_lsb(&mut self, data: &[u8], bit_len: usize) {
+        self.digest_bits_in_order(data, bit_len, false);
+    }
+
+    /// Digest the lowest `bits` bits of a symbol, from the most significant one (MSB-first).
+    pub fn digest_symbol(&mut self, symbol: u64, bits: u8) {
+        debug_assert!(bits <= 128);
+
+        for i in (0..bits).rev() {
+            self.digest_bit((symbol >> i) & 1 != 0);
+        }
+    }
+
+    /// Digest the lowest `bits` bits of a symbol, from the least significant one (LSB-first).
+    pub fn digest_symbol_lsb(&mut self, symbol: u64, bits: u8) {
+        debug_assert!(bits <= 128);
+
+        for i in 0..bits {
+            self.digest_bit((symbol >> i) & 1 != 0);
+        }
+    }
+
+    fn digest_bits_in_order(&mut self, data: &[u8], bit_len: usize, msb_first: bool) {
+        debug_assert!(bit_len <= data.len() * 8);
+
+        let (bytes, rest) = data.split_at(bit_len / 8);
+
+        if msb_first == self.reflect {
+            // the lookup tables take the bits of a byte in the other order
+            let mut buffer = [0u8; 64];
+
+            for chunk in bytes.chunks(64) {
+                for (b, n) in buffer.iter_mut().zip(chunk.iter()) {
+                    *b = n.reverse_bits();
+                }
+
+                self.digest(&buffer[..chunk.len()]);
+            }
+        } else {
+            self.digest(bytes);
+        }
+
+        let rest_bits = (bit_len % 8) as u8;
+
+        if rest_bits > 0 {
+            if msb_first {
+                self.digest_symbol((rest[0] >> (8 - rest_bits)) as u64, rest_bits);
+            } else {
+                self.digest_symbol_lsb(rest[0] as u64, rest_bits);
+            }
+        }
+    }
+
+    #[inline]
+    fn digest_bit(&mut self, bit: bool) {
+        if self.reflect {
+            let carry = (self.sum & 1 != 0) != bit;
+
+            self.sum >>= 1;
+
+            if carry {
+                self.sum ^= self.poly;
+            }
+        } else {
+            let carry = ((self.sum >> (self.bits - 1)) & 1 != 0) != bit;
+
+            self.sum <<= 1;
+
+            if carry {
+                self.sum ^= self.poly;
+            }
+        }
+    }
+
+    /// Digest a slice of data. This function can be used in a const context.
+    pub const fn digest_slice(&mut self, data: &[u8]) {
+        let lookup_table = self.lookup_table.get();
+
+        let mut i = 0;
+
+        if self.bits == 8 {
+            while i < data.len() {
+                let index = (self.sum as u8 ^ data[i]) as usize;
+                self.sum = lookup_table[index];
+
+                i += 1;
+            }
+        } else if self.reflect {
+            while i < data.len() {
+                let index = ((self.sum as u8) ^ data[i]) as usize;
+                self.sum = (self.sum >> 8) ^ lookup_table[index];
+
+                i += 1;
+            }
+        } else if self.bits < 8 {
+            // a register narrower than a byte is left-aligned inside the index
+            let shift = 8 - self.bits as u64;
+
+            while i < data.len() {
+                let index = ((self.sum << shift) as u8 ^ data[i]) as usize;
+                self.sum = lookup_table[index];
+
+                i += 1;
+            }
+        } else {
+            let shift = (self.bits - 8) as u64;
+
+            while i < data.len() {
+                let index = ((self.sum >> shift) as u8 ^ data[i]) as usize;
+                self.sum = (self.sum << 8) ^ lookup_table[index];
+
+                i += 1;
+            }
+        }
+    }
+
+    /// Reset the sum.
+    pub const fn reset(&mut self) {
+        self.sum = if self.reflect {
+            Self::reflect_function(1 << (self.bits - 1), self.initial)
+        } else {
+            self.initial
+        };
+    }
+
+    /// Get the current CRC value (it always returns a `u128` value). You can continue calling `digest` method even after getting a CRC value.
+    pub const fn get_crc(&self) -> u128 {
+        (self.reflect_output(self.sum) ^ self.final_xor) & self.mask
+    }
+
+    /// Get the length of bits.
+    #[inline]
+    pub const fn width(&self) -> u8 {
+        self.bits
+    }
+
+    /// Get the expression, which is not reflected even if the input is reflected.
+    #[inline]
+    pub const fn poly(&self) -> u128 {
+        if self.reflect {
+            Self::reflect_function(1 << (self.bits - 1), self.poly)
+        } else {
+            self.poly
+        }
+    }
+
+    /// Get the initial value.
+    #[inline]
+    pub const fn init(&self) -> u128 {
+        self.initial
+    }
+
+    /// Whether the input is reflected.
+    #[inline]
+    pub const fn refin(&self) -> bool {
+        self.reflect
+    }
+
+    /// Whether the output is reflected.
+    #[inline]
+    pub const fn refout(&self) -> bool {
+        self.reflect_out
+    }
+
+    /// Get the final xor value.
+    #[inline]
+    pub const fn xorout(&self) -> u128 {
+        self.final_xor
+    }
+
+    /// Get the parameters of this instance, along with the check value and the residue. The name is empty.
+    pub const fn parameters(&self) -> CRCParameters {
+        CRCParametersBuilder::new(self.bits, self.poly())
+            .init(self.initial)
+            .refin(self.reflect)
+            .refout(self.reflect_out)
+            .xorout(self.final_xor)
+            .build()
+    }
+
+    /// Combine the CRC values of two adjacent blocks of data into the CRC value of the concatenated data, without the data. `len_b` is the length of the second block in bytes. Only the parameters of this instance are used, not its current state.
+    pub fn combine(&self, crc_a: u128, crc_b: u128, len_b: u64) -> u128 {
+        let high_bit = 1 << (self.bits - 1);
+
+        let initial = if self.reflect {
+            Self::reflect_function(high_bit, self.initial)
+        } else {
+            self.initial
+        };
+
+        // the registers of the concatenated data and of the second block differ in the register of the first block (xored with the initial value) followed by `len_b` zero bytes
+        let mut shifted = (self.reflect_output(crc_a ^ self.final_xor) ^ initial) & self.mask;
+
+        let poly = if self.reflect {
+            shifted = Self::reflect_function(high_bit, shifted);
+
+            Self::reflect_function(high_bit, self.poly)
+        } else {
+            self.poly
+        };
+
+        // x^(8 * len_b) mod P
+        let mut power = 1;
+        let mut square = 1;
+
+        for _ in 0..8 {
+            square = if square & high_bit != 0 {
+                ((square << 1) ^ poly) & self.mask
+            } else {
+                (square << 1) & self.mask
+            };
+        }
+
+        let mut len_b = len_b;
+
+        while len_b != 0 {
+            if len_b & 1 != 0 {
+                power = Self::multiply_mod(poly, self.bits, power, square);
+            }
+
+            square = Self::multiply_mod(poly, self.bits, square, square);
+
+            len_b >>= 1;
+        }
+
+        shifted = Self::multiply_mod(poly, self.bits, shifted, power);
+
+        if self.reflect {
+            shifted = Self::reflect_function(high_bit, shifted);
+        }
+
+        (crc_b ^ self.reflect_output(shifted)) & self.mask
+    }
+
+    /// Multiply two polynomials (in the non-reflected form) modulo the CRC polynomial.
+    fn multiply_mod(poly: u128, bits: u8, a: u128, b: u128) -> u128 {
+        let high_bit = 1 << (bits - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+
+        let mut a = a;
+        let mut product = 0;
+
+        for i in 0..bits {
+            if (b >> i) & 1 != 0 {
+                product ^= a;
+            }
+
+            a = if a & high_bit != 0 {
+                ((a << 1) ^ poly) & mask
+            } else {
+                (a << 1) & mask
+            };
+        }
+
+        product
+    }
+
+    /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `digest` method even after getting a CRC value.
+#[cfg(feature = "default")]
+    pub fn get_crc_vec_le(&mut self) -> Vec<u8> {
+        let e = ((self.bits as f64 + 7f64) / 8f64) as u64;
+
+        let e_dec = e - 1;
+
+        let mut vec = Vec::with_capacity(e as usize);
+
+        let crc = self.get_crc();
+
+        let o = e_dec * 8;
+
+        for i in 0..e {
+            vec.push((crc << ((e_dec - i) * 8) >> o) as u8);
+        }
+
+        vec
+    }
+
+    /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `digest` method even after getting a CRC value.
+#[cfg(feature = "default")]
+    pub fn get_crc_vec_be(&mut self) -> Vec<u8> {
+        let e = ((self.bits as f64 + 7f64) / 8f64) as u64;
+
+        let e_dec = e - 1;
+
+        let mut vec = Vec::with_capacity(e as usize);
+
+        let crc = self.get_crc();
+
+        let o = e_dec * 8;
+
+        for i in 0..e {
+            vec.push((crc << (i * 8) >> o) as u8);
+        }
+
+        vec
+    }
+
+    /// Write the current CRC value into the beginning of `buffer` in the given byte order, and return the number of written bytes, which corresponds to the CRC bits. It panics if `buffer` is too short.
+    pub fn write_crc(&self, buffer: &mut [u8], endian: Endian) -> usize {
+        let e = (self.bits as usize).div_ceil(8);
+
+        let bytes = self.get_crc().to_be_bytes();
+        let bytes = &bytes[bytes.len() - e..];
+
+        match endian {
+            Endian::Big => buffer[..e].copy_from_slice(bytes),
+            Endian::Little => {
+                for (b, c) in buffer[..e].iter_mut().zip(bytes.iter().rev()) {
+                    *b = *c;
+                }
+            },
+        }
+
+        e
+    }
+
+    /// Append the current CRC value to `buffer` in the given byte order.
+    #[cfg(feature = "default")]
+    pub fn append_crc(&self, buffer: &mut Vec<u8>, endian: Endian) {
+        let length = buffer.len();
+
+        buffer.resize(length + (self.bits as usize).div_ceil(8), 0);
+
+        self.write_crc(&mut buffer[length..], endian);
+    }
+
+    /// Check whether `frame` is some data followed by its CRC value in the given byte order. The data is digested by a clone of this instance, so this instance is not changed.
+    pub fn verify_frame(&self, frame: &[u8], endian: Endian) -> bool {
+        let e = (self.bits as usize).div_ceil(8);
+
+        if frame.len() < e {
+            return false;
+        }
+
+        let (data, crc) = frame.split_at(frame.len() - e);
+
+        let mut this = self.clone();
+
+        this.digest(data);
+
+        let mut expected = [0u8; 16];
+
+        this.write_crc(&mut expected, endian);
+
+        expected[..e] == *crc
+    }
+
+    /// Check whether the data digested so far is followed by its own CRC value, by comparing the current CRC value with the residue (xored with the final xor value) of the algorithm, so a frame can be verified in one pass. The CRC value must be in the byte order which matches the reflection (`Endian::Little` if reflected, `Endian::Big` otherwise), and the length of bits must be a multiple of 8.
+    pub const fn check_residue(&self) -> bool {
+        self.get_crc() == residue(self.bits, self.poly(), self.reflect_out, self.final_xor) ^ self.final_xor
+    }
+
+    /// Generate the lookup table of a reflected CRC by providing its reflected expression. This function can be used in a const context.
+    pub const fn crc_reflect_table(poly_rev: u128) -> [u128; 256] {
+        let mut lookup_table = [0u128; 256];
+
+        let mut i = 0;
+
+        while i < 256 {
+            let mut v = i as u128;
+
+            let mut j = 0;
+
+            while j < 8 {
+                if v & 1 != 0 {
+                    v >>= 1;
+                    v ^= poly_rev;
+                } else {
+                    v >>= 1;
+                }
+
+                j += 1;
+            }
+
+            lookup_table[i] = v;
+
+            i += 1;
+        }
+
+        lookup_table
+    }
+
+    /// Generate the lookup table of a non-reflected CRC by providing its expression and the length of bits. This function can be used in a const context.
+    pub const fn crc_table(poly: u128, bits: u8) -> [u128; 256] {
+        let mut lookup_table = [0u128; 256];
+
+        let mask1 = 1u128 << (bits - 1);
+
+        let mask2 = ((mask1 - 1) << 1) | 1;
+
+        let mut i = 0;
+
+        while i < 256 {
+            let mut v = 0u128;
+
+            let mut j = 8;
+
+            while j > 0 {
+                j -= 1;
+
+                let bit = (v & mask1 != 0) != ((i >> j) & 1 != 0);
+
+                v <<= 1;
+
+                if bit {
+                    v ^= poly;
+                }
+            }
+
+            lookup_table[i] = v & mask2;
+
+            i += 1;
+        }
+
+        lookup_table
+    }
+
+}
+
+
+static REF_82_00000000000220808A00A2022200C430: [u128; 256] = CRCu128::crc_reflect_table(0x00000000000220808A00A2022200C430);
+
+impl CRCu128 {
+    pub const fn crc82darc() -> CRCu128 {
+        // Self::create_crc(0x00000000000220808A00A2022200C430, 82, 0x00000000000000000000000000000000, 0x00000000000000000000000000000000, true)
+
+        let lookup_table = &REF_82_00000000000220808A00A2022200C430;
+        Self::create_crc_with_lookup_table(lookup_table, 82, 0x00000000000000000000000000000000, 0x00000000000000000000000000000000, true)
+    }
+}
+
+#[cfg(all(feature = "development", test))]
+mod tests {
+    use super::CRCu128;
+
+    use std::fmt::Write;
+
+    #[test]
+    fn print_lookup_table() {
+        let crc = CRCu128::crc82darc();
+
+        let mut s = String::new();
+
+        for n in crc.lookup_table.iter().take(255) {
+            s.write_fmt(format_args!("{}u128, ", n)).unwrap();
+        }
+
+        s.write_fmt(format_args!("{}u128", crc.lookup_table[255])).unwrap();
+
+        println!("let lookup_table = [{}];", s);
+    }
+}
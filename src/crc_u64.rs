@@ -3,30 +3,42 @@ use alloc::vec::Vec;
 #[cfg(feature = "default")]
 use alloc::fmt::{self, Formatter, Display, Debug};
 
+use core::hash::Hasher;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use parameters::{residue, CRCParameters, CRCParametersBuilder};
+use error::{CRCError, check_parameters};
+use table::Table;
+use endian::Endian;
+
+#[cfg(target_arch = "x86_64")]
+use pclmul::FoldingConstants;
+
 /// This struct can help you compute a CRC-64 (or CRC-x where **x** is under `64`) value.
+#[derive(Clone)]
 pub struct CRCu64 {
-    by_table: bool,
     poly: u64,
-    lookup_table: [u64; 256],
+    lookup_table: Table<[u64; 256]>,
+    slicing_tables: Option<&'static [[u64; 256]; 8]>,
     sum: u64,
     pub(crate) bits: u8,
-    high_bit: u64,
     mask: u64,
     initial: u64,
     final_xor: u64,
     reflect: bool,
+    reflect_out: bool,
     reorder: bool,
+    #[cfg(target_arch = "x86_64")]
+    folding_constants: Option<FoldingConstants>,
 }
 
 #[cfg(feature = "default")]
 impl Debug for CRCu64 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        if self.by_table {
-            impl_debug_for_struct!(CRCu64, f, self, let .lookup_table = self.lookup_table.as_ref(), (.sum, "0x{:016X}", self.sum), .bits, (.initial, "0x{:016X}", self.initial), (.final_xor, "0x{:016X}", self.final_xor), .reflect, .reorder);
-        } else {
-            impl_debug_for_struct!(CRCu64, f, self, (.poly, "0x{:016X}", self.poly), (.sum, "0x{:016X}", self.sum), .bits, (.initial, "0x{:016X}", self.initial), (.final_xor, "0x{:016X}", self.final_xor), .reflect, .reorder);
-        }
+        impl_debug_for_struct!(CRCu64, f, self, (.poly, "0x{:016X}", self.poly), let .lookup_table = &self.lookup_table[..], (.sum, "0x{:016X}", self.sum), .bits, (.initial, "0x{:016X}", self.initial), (.final_xor, "0x{:016X}", self.final_xor), .reflect, .reflect_out, .reorder);
     }
 }
 
@@ -38,63 +50,151 @@ impl Display for CRCu64 {
     }
 }
 
+impl Hasher for CRCu64 {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.get_crc()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.digest(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Write for CRCu64 {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.digest(buf);
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 impl CRCu64 {
-    /// Create a `CRCu64` instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
+    /// Create a `CRCu64` instance by providing the length of bits, expression, reflection, an initial value and a final xor value. Use `create_crc_with_lookup_table` in a const context.
     pub fn create_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
         debug_assert!(bits <= 64 && bits > 0);
 
-        if bits % 8 == 0 {
-            let lookup_table = if reflect {
-                Self::crc_reflect_table(poly)
-            } else {
-                Self::crc_table(poly, bits)
-            };
+        let lookup_table = if reflect {
+            Self::crc_reflect_table(poly)
+        } else {
+            Self::crc_table(poly, bits)
+        };
+
+        Self::create_crc_with_table(Table::new(lookup_table), bits, initial, final_xor, reflect)
+    }
+
+    /// Create a `CRCu64` instance by providing the length of bits, expression, reflection of the input and the output, an initial value and a final xor value. The expression is reflected if `reflect_in` is `true`, like the one which `create_crc` accepts.
+    #[inline]
+    pub fn create_crc_with_reflection(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect_in: bool, reflect_out: bool) -> CRCu64 {
+        let mut crc = Self::create_crc(poly, bits, initial, final_xor, reflect_in);
+
+        crc.reflect_out = reflect_out;
 
-            Self::create_crc_with_exists_lookup_table(lookup_table, bits, initial, final_xor, reflect)
+        crc
+    }
+
+    /// Create a `CRCu64` instance by providing the parameters of an algorithm.
+    #[inline]
+    pub fn create_crc_with_parameters(parameters: &CRCParameters) -> CRCu64 {
+        debug_assert!(parameters.width <= 64 && parameters.width > 0);
+
+        let poly = parameters.poly as u64;
+
+        let poly = if parameters.refin {
+            Self::reflect_function(1 << (parameters.width - 1), poly)
         } else {
-            Self::create(false, [0u64; 256], poly, bits, initial, final_xor, reflect)
-        }
+            poly
+        };
+
+        Self::create_crc_with_reflection(poly, parameters.width, parameters.init as u64, parameters.xorout as u64, parameters.refin, parameters.refout)
+    }
+
+    /// Create a `CRCu64` instance like `create_crc`, but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> Result<CRCu64, CRCError> {
+        check_parameters(poly as u128, bits, 64, initial as u128, final_xor as u128, reflect)?;
+
+        Ok(Self::create_crc(poly, bits, initial, final_xor, reflect))
+    }
+
+    /// Create a `CRCu64` instance like `create_crc_with_reflection`, but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc_with_reflection(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect_in: bool, reflect_out: bool) -> Result<CRCu64, CRCError> {
+        check_parameters(poly as u128, bits, 64, initial as u128, final_xor as u128, reflect_in)?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect_in, reflect_out))
     }
 
-    /// Create a `CRCu64` instance by providing an existing lookup table, the length of bits, expression, reflection, an initial value and a final xor value.
-    pub(crate) fn create_crc_with_exists_lookup_table(lookup_table: [u64; 256], bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
-        debug_assert!(bits % 8 == 0);
+    /// Create a `CRCu64` instance like `create_crc_with_parameters`, but return an error instead of creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc_with_parameters(parameters: &CRCParameters) -> Result<CRCu64, CRCError> {
+        check_parameters(parameters.poly, parameters.width, 64, parameters.init, parameters.xorout, false)?;
 
-        Self::create(true, lookup_table, 0, bits, initial, final_xor, reflect)
+        Ok(Self::create_crc_with_parameters(parameters))
     }
 
+    /// Create a `CRCu64` instance by providing a static lookup table, the length of bits, reflection, an initial value and a final xor value. The lookup table can be generated by the `crc_table` (or `crc_reflect_table`) function at compile time, so this function can be used to create a `static` instance.
     #[inline]
-    fn create(by_table: bool, lookup_table: [u64; 256], mut poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
-        let high_bit = 1 << (bits as u64 - 1);
+    pub const fn create_crc_with_lookup_table(lookup_table: &'static [u64; 256], bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
+        debug_assert!(bits <= 64 && bits > 0);
+
+        Self::create_crc_with_table(Table::Static(lookup_table), bits, initial, final_xor, reflect)
+    }
+
+    const fn create_crc_with_table(lookup_table: Table<[u64; 256]>, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
+        let high_bit = 1 << (bits - 1);
         let mask = ((high_bit - 1) << 1) | 1;
 
+        // the polynomial of a lookup table can be found at index 1 (or index 128 for a reflected one)
+        let poly = if reflect {
+            lookup_table.get()[0x80]
+        } else {
+            lookup_table.get()[1]
+        };
+
         let sum = if reflect {
             Self::reflect_function(high_bit, initial)
         } else {
             initial
         };
 
-        if !by_table && reflect {
-            poly = Self::reflect_function(high_bit, poly);
-        }
-
         CRCu64 {
-            by_table,
             poly,
             lookup_table,
+            slicing_tables: None,
             sum,
             bits,
-            high_bit,
             mask,
             initial,
             final_xor,
             reflect,
+            reflect_out: reflect,
             reorder: false,
+            #[cfg(target_arch = "x86_64")]
+            folding_constants: None,
         }
     }
 
+    /// Create a `CRCu64` instance by providing an existing lookup table and its slicing-by-8 tables, the length of bits, expression, reflection, an initial value and a final xor value.
+    #[inline]
+    pub(crate) const fn create_crc_with_exists_slicing_tables(lookup_table: &'static [u64; 256], slicing_tables: &'static [[u64; 256]; 8], bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRCu64 {
+        let mut crc = Self::create_crc_with_lookup_table(lookup_table, bits, initial, final_xor, reflect);
+
+        crc.slicing_tables = Some(slicing_tables);
+
+        crc
+    }
+
     #[inline]
-    fn reflect_function(high_bit: u64, n: u64) -> u64 {
+    const fn reflect_function(high_bit: u64, n: u64) -> u64 {
         let mut i = high_bit;
         let mut j = 1;
         let mut out = 0;
@@ -111,112 +211,453 @@ impl CRCu64 {
         out
     }
 
+    /// Reflect a register value if the output is not reflected in the same way as the input (or the other way around, as the reflection is its own inverse).
     #[inline]
-    fn reflect_method(&self, n: u64) -> u64 {
-        Self::reflect_function(self.high_bit, n)
+    const fn reflect_output(&self, sum: u64) -> u64 {
+        if self.reflect == self.reflect_out {
+            sum
+        } else {
+            Self::reflect_function(1 << (self.bits - 1), sum)
+        }
     }
 
-    /// Digest some data.
+    /// Digest some data. Use `digest_slice` in a const context.
     pub fn digest<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
-        if self.by_table {
-            if self.bits == 8 {
-                for &n in data.as_ref() {
-                    let index = (self.sum as u8 ^ n) as usize;
-                    self.sum = self.lookup_table[index];
-                }
-            } else {
-                if self.reflect {
-                    for &n in data.as_ref() {
-                        let index = ((self.sum as u8) ^ n) as usize;
-                        self.sum = (self.sum >> 8) ^ self.lookup_table[index];
-                    }
+        let data = data.as_ref();
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            if data.len() >= super::pclmul::MIN_LENGTH && super::pclmul::is_available() {
+                let head = if self.reflect {
+                    self.sum.to_le_bytes()
                 } else {
-                    for &n in data.as_ref() {
-                        let index = ((self.sum >> ((self.bits - 8) as u64)) as u8 ^ n) as usize;
-                        self.sum = (self.sum << 8) ^ self.lookup_table[index];
+                    ((self.sum & self.mask) << (64 - self.bits)).to_be_bytes()
+                };
+
+                let folding_constants = match self.folding_constants {
+                    Some(folding_constants) => folding_constants,
+                    None => {
+                        let poly = if self.reflect {
+                            Self::reflect_function(1 << (self.bits - 1), self.poly)
+                        } else {
+                            self.poly
+                        };
+
+                        let folding_constants = FoldingConstants::new(poly, self.bits, self.reflect);
+
+                        self.folding_constants = Some(folding_constants);
+
+                        folding_constants
                     }
-                }
+                };
+
+                let (folded, remainder) = unsafe { super::pclmul::fold(&folding_constants, head, data) };
+
+                self.sum = 0;
+
+                self.digest(&folded);
+                self.digest(remainder);
+
+                return;
             }
+        }
+
+        let data = match self.slicing_tables {
+            Some(slicing_tables) if self.reflect => self.digest_reflect_slicing(slicing_tables, data),
+            Some(slicing_tables) => self.digest_slicing(slicing_tables, data),
+            None => data,
+        };
+
+        self.digest_slice(data);
+    }
+
+    /// Digest some data by using multiple threads. Large data is split into chunks whose CRC values are computed in parallel and then combined, so the result is the same as the one of the `digest` method.
+    #[cfg(feature = "rayon")]
+    pub fn digest_parallel<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
+        let data = data.as_ref();
+
+        if data.len() < super::parallel::MIN_LENGTH {
+            self.digest(data);
+
+            return;
+        }
+
+        let crc_b = super::parallel::digest(
+            data,
+            |chunk| {
+                let mut crc = self.clone();
+
+                crc.reset();
+                crc.digest(chunk);
+
+                crc.get_crc()
+            },
+            |crc_a, crc_b, len_b| self.combine(crc_a, crc_b, len_b),
+        );
+
+        let crc = self.combine(self.get_crc(), crc_b, data.len() as u64);
+
+        let crc = if self.reorder {
+            Self::reorder_function(self.bits, crc)
         } else {
-            if self.reflect {
-                for &n in data.as_ref() {
-                    let n = super::crc_u8::CRCu8::reflect_function(0x80, n);
+            crc
+        };
 
-                    let mut i = 0x80;
+        self.sum = self.reflect_output((crc ^ self.final_xor) & self.mask);
+    }
 
-                    while i != 0 {
-                        let mut bit = self.sum & self.high_bit;
+    /// Digest the first `bit_len` bits of some data, taking the bits of each byte from the most significant one (MSB-first). For a non-reflected CRC, digesting whole bytes in this way is the same as calling the `digest` method.
+    pub fn digest_bits(&mut self, data: &[u8], bit_len: usize) {
+        self.digest_bits_in_order(data, bit_len, true);
+    }
 
-                        self.sum <<= 1;
+    /// Digest the first `bit_len` bits of some data, taking the bits of each byte from the least significant one (LSB-first). For a reflected CRC, digesting whole bytes in this way is the same as calling the `digest` method.
+    pub fn digest_bits_lsb(&mut self, data: &[u8], bit_len: usize) {
+        self.digest_bits_in_order(data, bit_len, false);
+    }
 
-                        if n & i != 0 {
-                            bit ^= self.high_bit;
-                        }
+    /// Digest the lowest `bits` bits of a symbol, from the most significant one (MSB-first).
+    pub fn digest_symbol(&mut self, symbol: u64, bits: u8) {
+        debug_assert!(bits <= 64);
 
+        for i in (0..bits).rev() {
+            self.digest_bit((symbol >> i) & 1 != 0);
+        }
+    }
 
-                        if bit != 0 {
-                            self.sum ^= self.poly;
-                        }
+    /// Digest the lowest `bits` bits of a symbol, from the least significant one (LSB-first).
+    pub fn digest_symbol_lsb(&mut self, symbol: u64, bits: u8) {
+        debug_assert!(bits <= 64);
 
-                        i >>= 1;
-                    }
+        for i in 0..bits {
+            self.digest_bit((symbol >> i) & 1 != 0);
+        }
+    }
+
+    fn digest_bits_in_order(&mut self, data: &[u8], bit_len: usize, msb_first: bool) {
+        debug_assert!(bit_len <= data.len() * 8);
+
+        let (bytes, rest) = data.split_at(bit_len / 8);
+
+        if msb_first == self.reflect {
+            // the lookup tables take the bits of a byte in the other order
+            let mut buffer = [0u8; 64];
+
+            for chunk in bytes.chunks(64) {
+                for (b, n) in buffer.iter_mut().zip(chunk.iter()) {
+                    *b = n.reverse_bits();
                 }
+
+                self.digest(&buffer[..chunk.len()]);
+            }
+        } else {
+            self.digest(bytes);
+        }
+
+        let rest_bits = (bit_len % 8) as u8;
+
+        if rest_bits > 0 {
+            if msb_first {
+                self.digest_symbol((rest[0] >> (8 - rest_bits)) as u64, rest_bits);
             } else {
-                for &n in data.as_ref() {
-                    let mut i = 0x80;
+                self.digest_symbol_lsb(rest[0] as u64, rest_bits);
+            }
+        }
+    }
 
-                    while i != 0 {
-                        let mut bit = self.sum & self.high_bit;
+    #[inline]
+    fn digest_bit(&mut self, bit: bool) {
+        if self.reflect {
+            let carry = (self.sum & 1 != 0) != bit;
 
-                        self.sum <<= 1;
+            self.sum >>= 1;
 
-                        if n & i != 0 {
-                            bit ^= self.high_bit;
-                        }
+            if carry {
+                self.sum ^= self.poly;
+            }
+        } else {
+            let carry = ((self.sum >> (self.bits - 1)) & 1 != 0) != bit;
 
-                        if bit != 0 {
-                            self.sum ^= self.poly;
-                        }
+            self.sum <<= 1;
 
-                        i >>= 1;
-                    }
-                }
+            if carry {
+                self.sum ^= self.poly;
+            }
+        }
+    }
+
+    /// Digest a slice of data. This function can be used in a const context. It does not use the slicing tables or the hardware acceleration which `digest` uses.
+    pub const fn digest_slice(&mut self, data: &[u8]) {
+        let lookup_table = self.lookup_table.get();
+
+        let mut i = 0;
+
+        if self.bits == 8 {
+            while i < data.len() {
+                let index = (self.sum as u8 ^ data[i]) as usize;
+                self.sum = lookup_table[index];
+
+                i += 1;
+            }
+        } else if self.reflect {
+            while i < data.len() {
+                let index = ((self.sum as u8) ^ data[i]) as usize;
+                self.sum = (self.sum >> 8) ^ lookup_table[index];
+
+                i += 1;
+            }
+        } else if self.bits < 8 {
+            // a register narrower than a byte is left-aligned inside the index
+            let shift = 8 - self.bits as u64;
+
+            while i < data.len() {
+                let index = ((self.sum << shift) as u8 ^ data[i]) as usize;
+                self.sum = lookup_table[index];
+
+                i += 1;
+            }
+        } else {
+            let shift = (self.bits - 8) as u64;
+
+            while i < data.len() {
+                let index = ((self.sum >> shift) as u8 ^ data[i]) as usize;
+                self.sum = (self.sum << 8) ^ lookup_table[index];
+
+                i += 1;
+            }
+        }
+    }
+
+    /// Digest as many 8-byte blocks as possible by using the slicing-by-8 tables of a reflected CRC, and return the remaining data.
+    fn digest_reflect_slicing<'a>(&mut self, slicing_tables: &[[u64; 256]; 8], data: &'a [u8]) -> &'a [u8] {
+        let mut chunks = data.chunks_exact(8);
+
+        for chunk in &mut chunks {
+            let mut block = [0u8; 8];
+
+            block.copy_from_slice(chunk);
+
+            for (b, s) in block.iter_mut().zip(self.sum.to_le_bytes().iter()) {
+                *b ^= s;
+            }
+
+            let mut sum = 0;
+
+            for (i, &b) in block.iter().enumerate() {
+                sum ^= slicing_tables[7 - i][b as usize];
+            }
+
+            self.sum = sum;
+        }
+
+        chunks.remainder()
+    }
+
+    /// Digest as many 8-byte blocks as possible by using the (left-aligned) slicing-by-8 tables of a non-reflected CRC, and return the remaining data.
+    fn digest_slicing<'a>(&mut self, slicing_tables: &[[u64; 256]; 8], data: &'a [u8]) -> &'a [u8] {
+        let shift = 64 - self.bits as u64;
+
+        let mut sum = self.sum << shift;
+
+        let mut chunks = data.chunks_exact(8);
+
+        for chunk in &mut chunks {
+            let mut block = [0u8; 8];
+
+            block.copy_from_slice(chunk);
+
+            for (b, s) in block.iter_mut().zip(sum.to_be_bytes().iter()) {
+                *b ^= s;
+            }
+
+            sum = 0;
+
+            for (i, &b) in block.iter().enumerate() {
+                sum ^= slicing_tables[7 - i][b as usize];
             }
         }
+
+        self.sum = sum >> shift;
+
+        chunks.remainder()
     }
 
     /// Reset the sum.
-    pub fn reset(&mut self) {
-        self.sum = self.initial;
+    pub const fn reset(&mut self) {
+        self.sum = if self.reflect {
+            Self::reflect_function(1 << (self.bits - 1), self.initial)
+        } else {
+            self.initial
+        };
     }
 
     /// Get the current CRC value (it always returns a `u64` value). You can continue calling `digest` method even after getting a CRC value.
-    pub fn get_crc(&self) -> u64 {
-        let sum = if self.by_table {
-            (self.sum ^ self.final_xor) & self.mask
+    pub const fn get_crc(&self) -> u64 {
+        let sum = (self.reflect_output(self.sum) ^ self.final_xor) & self.mask;
+
+        if self.reorder {
+            Self::reorder_function(self.bits, sum)
+        } else {
+            sum
+        }
+    }
+
+    /// Reverse the order of the bytes of a CRC value.
+    const fn reorder_function(bits: u8, sum: u64) -> u64 {
+        let mut new_sum = 0;
+
+        let e = ((bits as f64 + 7f64) / 8f64) as u64;
+
+        let e_dec = e - 1;
+
+        let mut i = 0;
+
+        while i < e {
+            new_sum |= ((sum >> ((e_dec - i) * 8)) & 0xFF) << (i * 8);
+
+            i += 1;
+        }
+
+        new_sum
+    }
+
+    /// Get the length of bits.
+    #[inline]
+    pub const fn width(&self) -> u8 {
+        self.bits
+    }
+
+    /// Get the expression, which is not reflected even if the input is reflected.
+    #[inline]
+    pub const fn poly(&self) -> u64 {
+        if self.reflect {
+            Self::reflect_function(1 << (self.bits - 1), self.poly)
+        } else {
+            self.poly
+        }
+    }
+
+    /// Get the initial value.
+    #[inline]
+    pub const fn init(&self) -> u64 {
+        self.initial
+    }
+
+    /// Whether the input is reflected.
+    #[inline]
+    pub const fn refin(&self) -> bool {
+        self.reflect
+    }
+
+    /// Whether the output is reflected.
+    #[inline]
+    pub const fn refout(&self) -> bool {
+        self.reflect_out
+    }
+
+    /// Get the final xor value.
+    #[inline]
+    pub const fn xorout(&self) -> u64 {
+        self.final_xor
+    }
+
+    /// Get the parameters of this instance, along with the check value and the residue. The name is empty.
+    pub const fn parameters(&self) -> CRCParameters {
+        CRCParametersBuilder::new(self.bits, self.poly() as u128)
+            .init(self.initial as u128)
+            .refin(self.reflect)
+            .refout(self.reflect_out)
+            .xorout(self.final_xor as u128)
+            .build()
+    }
+
+    /// Combine the CRC values of two adjacent blocks of data into the CRC value of the concatenated data, without the data. `len_b` is the length of the second block in bytes. Only the parameters of this instance are used, not its current state.
+    pub fn combine(&self, crc_a: u64, crc_b: u64, len_b: u64) -> u64 {
+        let (crc_a, crc_b) = if self.reorder {
+            (Self::reorder_function(self.bits, crc_a), Self::reorder_function(self.bits, crc_b))
+        } else {
+            (crc_a, crc_b)
+        };
+
+        let high_bit = 1 << (self.bits - 1);
+
+        let initial = if self.reflect {
+            Self::reflect_function(high_bit, self.initial)
+        } else {
+            self.initial
+        };
+
+        // the registers of the concatenated data and of the second block differ in the register of the first block (xored with the initial value) followed by `len_b` zero bytes
+        let mut shifted = (self.reflect_output(crc_a ^ self.final_xor) ^ initial) & self.mask;
+
+        let poly = if self.reflect {
+            shifted = Self::reflect_function(high_bit, shifted);
+
+            Self::reflect_function(high_bit, self.poly)
         } else {
-            if self.reflect {
-                (self.reflect_method(self.sum) ^ self.final_xor) & self.mask
+            self.poly
+        };
+
+        // x^(8 * len_b) mod P
+        let mut power = 1;
+        let mut square = 1;
+
+        for _ in 0..8 {
+            square = if square & high_bit != 0 {
+                ((square << 1) ^ poly) & self.mask
             } else {
-                (self.sum ^ self.final_xor) & self.mask
+                (square << 1) & self.mask
+            };
+        }
+
+        let mut len_b = len_b;
+
+        while len_b != 0 {
+            if len_b & 1 != 0 {
+                power = Self::multiply_mod(poly, self.bits, power, square);
             }
-        };
+
+            square = Self::multiply_mod(poly, self.bits, square, square);
+
+            len_b >>= 1;
+        }
+
+        shifted = Self::multiply_mod(poly, self.bits, shifted, power);
+
+        if self.reflect {
+            shifted = Self::reflect_function(high_bit, shifted);
+        }
+
+        let crc = (crc_b ^ self.reflect_output(shifted)) & self.mask;
 
         if self.reorder {
-            let mut new_sum = 0;
+            Self::reorder_function(self.bits, crc)
+        } else {
+            crc
+        }
+    }
 
-            let e = ((self.bits as f64 + 7f64) / 8f64) as u64;
+    /// Multiply two polynomials (in the non-reflected form) modulo the CRC polynomial.
+    fn multiply_mod(poly: u64, bits: u8, a: u64, b: u64) -> u64 {
+        let high_bit = 1 << (bits - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
 
-            let e_dec = e - 1;
+        let mut a = a;
+        let mut product = 0;
 
-            for i in 0..e {
-                new_sum |= ((sum >> ((e_dec - i) * 8)) & 0xFF) << (i * 8);
+        for i in 0..bits {
+            if (b >> i) & 1 != 0 {
+                product ^= a;
             }
 
-            new_sum
-        } else {
-            sum
+            a = if a & high_bit != 0 {
+                ((a << 1) ^ poly) & mask
+            } else {
+                (a << 1) & mask
+            };
         }
+
+        product
     }
 
     /// Get the current CRC value (it always returns a vec instance with a length corresponding to the CRC bits). You can continue calling `digest` method even after getting a CRC value.
@@ -259,87 +700,272 @@ impl CRCu64 {
         vec
     }
 
-    fn crc_reflect_table(poly_rev: u64) -> [u64; 256] {
+    /// Write the current CRC value into the beginning of `buffer` in the given byte order, and return the number of written bytes, which corresponds to the CRC bits. It panics if `buffer` is too short.
+    pub fn write_crc(&self, buffer: &mut [u8], endian: Endian) -> usize {
+        let e = (self.bits as usize).div_ceil(8);
+
+        let bytes = self.get_crc().to_be_bytes();
+        let bytes = &bytes[bytes.len() - e..];
+
+        match endian {
+            Endian::Big => buffer[..e].copy_from_slice(bytes),
+            Endian::Little => {
+                for (b, c) in buffer[..e].iter_mut().zip(bytes.iter().rev()) {
+                    *b = *c;
+                }
+            },
+        }
+
+        e
+    }
+
+    /// Append the current CRC value to `buffer` in the given byte order.
+    #[cfg(feature = "default")]
+    pub fn append_crc(&self, buffer: &mut Vec<u8>, endian: Endian) {
+        let length = buffer.len();
+
+        buffer.resize(length + (self.bits as usize).div_ceil(8), 0);
+
+        self.write_crc(&mut buffer[length..], endian);
+    }
+
+    /// Check whether `frame` is some data followed by its CRC value in the given byte order. The data is digested by a clone of this instance, so this instance is not changed.
+    pub fn verify_frame(&self, frame: &[u8], endian: Endian) -> bool {
+        let e = (self.bits as usize).div_ceil(8);
+
+        if frame.len() < e {
+            return false;
+        }
+
+        let (data, crc) = frame.split_at(frame.len() - e);
+
+        let mut this = self.clone();
+
+        this.digest(data);
+
+        let mut expected = [0u8; 8];
+
+        this.write_crc(&mut expected, endian);
+
+        expected[..e] == *crc
+    }
+
+    /// Check whether the data digested so far is followed by its own CRC value, by comparing the current CRC value with the residue (xored with the final xor value) of the algorithm, so a frame can be verified in one pass. The CRC value must be in the byte order which matches the reflection (`Endian::Little` if reflected, `Endian::Big` otherwise), and the length of bits must be a multiple of 8.
+    pub const fn check_residue(&self) -> bool {
+        self.get_crc() as u128 == residue(self.bits, self.poly() as u128, self.reflect_out, self.final_xor as u128) ^ self.final_xor as u128
+    }
+
+    /// Generate the lookup table of a reflected CRC by providing its reflected expression. This function can be used in a const context.
+    pub const fn crc_reflect_table(poly_rev: u64) -> [u64; 256] {
         let mut lookup_table = [0u64; 256];
 
-        for i in 0..=255 {
+        let mut i = 0;
+
+        while i < 256 {
             let mut v = i as u64;
 
-            for _ in 0..8u8 {
+            let mut j = 0;
+
+            while j < 8 {
                 if v & 1 != 0 {
                     v >>= 1;
                     v ^= poly_rev;
                 } else {
                     v >>= 1;
                 }
+
+                j += 1;
             }
 
             lookup_table[i] = v;
+
+            i += 1;
         }
 
         lookup_table
     }
 
-    fn crc_table(poly: u64, bits: u8) -> [u64; 256] {
+    /// Generate the lookup table of a non-reflected CRC by providing its expression and the length of bits. This function can be used in a const context.
+    pub const fn crc_table(poly: u64, bits: u8) -> [u64; 256] {
         let mut lookup_table = [0u64; 256];
 
-        let mask1 = 1u64 << (bits - 1) as u64;
+        let mask1 = 1u64 << (bits - 1);
 
         let mask2 = ((mask1 - 1) << 1) | 1;
 
-        for i in 0..=255 {
-            let mut v = i as u64;
+        let mut i = 0;
 
-            for _ in 0..bits {
-                if v & mask1 == 0 {
-                    v <<= 1;
-                } else {
-                    v <<= 1;
+        while i < 256 {
+            let mut v = 0u64;
+
+            let mut j = 8;
+
+            while j > 0 {
+                j -= 1;
+
+                let bit = (v & mask1 != 0) != ((i >> j) & 1 != 0);
+
+                v <<= 1;
+
+                if bit {
                     v ^= poly;
                 }
             }
 
             lookup_table[i] = v & mask2;
+
+            i += 1;
         }
 
         lookup_table
     }
+
+    const fn crc_reflect_slicing_tables(lookup_table: &[u64; 256]) -> [[u64; 256]; 8] {
+        let mut slicing_tables = [[0u64; 256]; 8];
+
+        slicing_tables[0] = *lookup_table;
+
+        let mut k = 1;
+
+        while k < 8 {
+            let mut i = 0;
+
+            while i < 256 {
+                let v = slicing_tables[k - 1][i];
+
+                slicing_tables[k][i] = (v >> 8) ^ slicing_tables[0][(v & 0xFF) as usize];
+
+                i += 1;
+            }
+
+            k += 1;
+        }
+
+        slicing_tables
+    }
+
+    const fn crc_slicing_tables(lookup_table: &[u64; 256], bits: u8) -> [[u64; 256]; 8] {
+        let shift = 64 - bits as u64;
+
+        let mut slicing_tables = [[0u64; 256]; 8];
+
+        let mut i = 0;
+
+        while i < 256 {
+            slicing_tables[0][i] = lookup_table[i] << shift;
+
+            i += 1;
+        }
+
+        let mut k = 1;
+
+        while k < 8 {
+            let mut i = 0;
+
+            while i < 256 {
+                let v = slicing_tables[k - 1][i];
+
+                slicing_tables[k][i] = (v << 8) ^ slicing_tables[0][(v >> 56) as usize];
+
+                i += 1;
+            }
+
+            k += 1;
+        }
+
+        slicing_tables
+    }
 }
 
-const NO_REF_40_0000000004820009: [u64; 256] = [0u64, 75628553u64, 151257106u64, 226885659u64, 302514212u64, 378142765u64, 453771318u64, 529399871u64, 605028424u64, 546439233u64, 756285530u64, 697696339u64, 907542636u64, 848953445u64, 1058799742u64, 1000210551u64, 1210056848u64, 1285685401u64, 1092878466u64, 1168507019u64, 1512571060u64, 1588199613u64, 1395392678u64, 1471021231u64, 1815085272u64, 1756496081u64, 1697906890u64, 1639317699u64, 2117599484u64, 2059010293u64, 2000421102u64, 1941831911u64, 2420113696u64, 2495742249u64, 2571370802u64, 2646999355u64, 2185756932u64, 2261385485u64, 2337014038u64, 2412642591u64, 3025142120u64, 2966552929u64, 3176399226u64, 3117810035u64, 2790785356u64, 2732196165u64, 2942042462u64, 2883453271u64, 3630170544u64, 3705799097u64, 3512992162u64, 3588620715u64, 3395813780u64, 3471442333u64, 3278635398u64, 3354263951u64, 4235198968u64, 4176609777u64, 4118020586u64, 4059431395u64, 4000842204u64, 3942253013u64, 3883663822u64, 3825074631u64, 4840227392u64, 4899078729u64, 4991484498u64, 5050335835u64, 5142741604u64, 5201592941u64, 5293998710u64, 5352850047u64, 4371513864u64, 4296147457u64, 4522770970u64, 4447404563u64, 4674028076u64, 4598661669u64, 4825285182u64, 4749918775u64, 6050284240u64, 6109135577u64, 5933105858u64, 5991957195u64, 6352798452u64, 6411649789u64, 6235620070u64, 6294471407u64, 5581570712u64, 5506204305u64, 5464392330u64, 5389025923u64, 5884084924u64, 5808718517u64, 5766906542u64, 5691540135u64, 7260341088u64, 7319192425u64, 7411598194u64, 7470449531u64, 7025984324u64, 7084835661u64, 7177241430u64, 7236092767u64, 6791627560u64, 6716261153u64, 6942884666u64, 6867518259u64, 6557270796u64, 6481904389u64, 6708527902u64, 6633161495u64, 8470397936u64, 8529249273u64, 8353219554u64, 8412070891u64, 8236041172u64, 8294892509u64, 8118862790u64, 8177714127u64, 8001684408u64, 7926318001u64, 7884506026u64, 7809139619u64, 7767327644u64, 7691961237u64, 7650149262u64, 7574782855u64, 9680454784u64, 9756083337u64, 9798157458u64, 9873786011u64, 9982968996u64, 10058597549u64, 10100671670u64, 10176300223u64, 10285483208u64, 10226894017u64, 10403185882u64, 10344596691u64, 10587997420u64, 10529408229u64, 10705700094u64, 10647110903u64, 8743027728u64, 8818656281u64, 8592294914u64, 8667923467u64, 9045541940u64, 9121170493u64, 8894809126u64, 8970437679u64, 9348056152u64, 9289466961u64, 9197323338u64, 9138734147u64, 9650570364u64, 9591981173u64, 9499837550u64, 9441248359u64, 12100568480u64, 12176197033u64, 12218271154u64, 12293899707u64, 11866211716u64, 11941840269u64, 11983914390u64, 12059542943u64, 12705596904u64, 12647007713u64, 12823299578u64, 12764710387u64, 12471240140u64, 12412650949u64, 12588942814u64, 12530353623u64, 11163141424u64, 11238769977u64, 11012408610u64, 11088037163u64, 10928784660u64, 11004413213u64, 10778051846u64, 10853680399u64, 11768169848u64, 11709580657u64, 11617437034u64, 11558847843u64, 11533813084u64, 11475223893u64, 11383080270u64, 11324491079u64, 14520682176u64, 14579533513u64, 14638384850u64, 14697236187u64, 14823196388u64, 14882047725u64, 14940899062u64, 14999750399u64, 14051968648u64, 13976602241u64, 14169671322u64, 14094304915u64, 14354482860u64, 14279116453u64, 14472185534u64, 14396819127u64, 13583255120u64, 13642106457u64, 13432522306u64, 13491373643u64, 13885769332u64, 13944620669u64, 13735036518u64, 13793887855u64, 13114541592u64, 13039175185u64, 12963808778u64, 12888442371u64, 13417055804u64, 13341689397u64, 13266322990u64, 13190956583u64, 16940795872u64, 16999647209u64, 17058498546u64, 17117349883u64, 16706439108u64, 16765290445u64, 16824141782u64, 16882993119u64, 16472082344u64, 16396715937u64, 16589785018u64, 16514418611u64, 16237725580u64, 16162359173u64, 16355428254u64, 16280061847u64, 16003368816u64, 16062220153u64, 15852636002u64, 15911487339u64, 15769012052u64, 15827863389u64, 15618279238u64, 15677130575u64, 15534655288u64, 15459288881u64, 15383922474u64, 15308556067u64, 15300298524u64, 15224932117u64, 15149565710u64, 15074199303u64];
-const NO_REF_64_42F0E1EBA9EA3693: [u64; 256] = [0u64, 4823603603198064275u64, 9647207206396128550u64, 14344283933443513269u64, 5274672035359026399u64, 847670339082705484u64, 14759040976900489721u64, 10241823793177474922u64, 10549344070718052798u64, 15030250704074698541u64, 1695340678165410968u64, 6158653484774949387u64, 15804726273676621153u64, 11071337880091427826u64, 6824194888265062471u64, 2036903512645398228u64, 7367177604490692079u64, 2651944067726553980u64, 16419204125234161865u64, 11613757334439845466u64, 3390681356330821936u64, 7926053118503640995u64, 12317306969549898774u64, 16726154088988619397u64, 17607865585094646865u64, 13162708473643690690u64, 8194994013375312247u64, 3695931686473304036u64, 13648389776530124942u64, 18417527692557321757u64, 4073807025290796456u64, 8825348881154370363u64, 14734355208981384158u64, 10271039580541631821u64, 5303888135453107960u64, 822984195088142443u64, 9604374506261047041u64, 14391664176758772114u64, 47380625301539367u64, 4780770595170139316u64, 6781362712661643872u64, 2084283301222999283u64, 15852106237007281990u64, 11028505464239851989u64, 1670654249350217407u64, 6187869865390245932u64, 10578560694269006745u64, 15005564104267687178u64, 12269926345859042865u64, 16768987096479742114u64, 3433514057002836759u64, 7878672873577829764u64, 16389988026750624494u64, 11638443477897467005u64, 7391863372946608072u64, 2622728278751721819u64, 4044590402276644751u64, 8850035479350698268u64, 13673076206955870889u64, 18388311311405091898u64, 8147614050581592912u64, 3738764100714335683u64, 17650697762308740726u64, 13115328684529279205u64, 15709965168302367023u64, 11021966344253216700u64, 6909860770376862729u64, 2095335087373712026u64, 10607776270906215920u64, 15115916238825782115u64, 1645968390176284886u64, 6063892853452478021u64, 5216239979862816913u64, 762004938812542466u64, 14808413130408695223u64, 10336584279807992612u64, 94761250603078734u64, 4872975272980325085u64, 9561541190340278632u64, 14285852213486374907u64, 13562725425323287744u64, 18359094313119879763u64, 4168566602445998566u64, 8874722219015798645u64, 17657238303940757535u64, 13257468400305012364u64, 8136561383943382329u64, 3610266854770152362u64, 3341308498700434814u64, 7831293060043656173u64, 12375739730780491864u64, 16811819059476047563u64, 7452841817450123681u64, 2710377314828461874u64, 16324444680414493831u64, 11564384134825822740u64, 1621282580641819377u64, 6093108618008534114u64, 10636992411005044695u64, 15091230119249932612u64, 6867028114005673518u64, 2142715359940571325u64, 15757345747155659528u64, 10979133309658045851u64, 9518708972583580495u64, 14333231979791697372u64, 142141253402664553u64, 4830142882085382394u64, 14783726745893216144u64, 10365800689136969987u64, 5245456557503443638u64, 737318311902463013u64, 8089180804553289502u64, 3653099890976004493u64, 17700070958701396536u64, 13210088128275084459u64, 4139350461810230209u64, 8899408340202190162u64, 13587411233247080167u64, 18329878549100632180u64, 16295228101163185824u64, 11589070762272702515u64, 7477528201428671366u64, 2681160907110034709u64, 12328359726370364031u64, 16854651450907929836u64, 3384140715920324441u64, 7783913295349006794u64, 17796789492404876493u64, 12973186262895182430u64, 8294265019745835499u64, 3597188614796881784u64, 13819721540753725458u64, 18246723593521770113u64, 4190670174747424052u64, 8707887697765516199u64, 7249714899603402099u64, 2768808468102880224u64, 16248400991498780757u64, 11785088403942012614u64, 3291936780352569772u64, 8025325358597240639u64, 12127785706904956042u64, 16915077318774037017u64, 10432479959725633826u64, 15147713122803500977u64, 1524009877625084932u64, 6329456346323069591u64, 15705454305770282493u64, 11170082187107838830u64, 6635271944638132443u64, 2226424485906433608u64, 189522501206157468u64, 4634679410803088911u64, 9745950545960650170u64, 14245012653811987241u64, 5445476407655580739u64, 676338306971005648u64, 14876502445374089573u64, 10124960353263198198u64, 4215391513593610003u64, 8678706776937023872u64, 13790540925671641653u64, 18271444552530207910u64, 8337133204891997132u64, 3549843186494580063u64, 17749444438031597290u64, 13016054137459951737u64, 12170653315997410989u64, 16867732534171963454u64, 3244592164593781643u64, 8068192726900473112u64, 16273122767886764658u64, 11755906975779290337u64, 7220533709540304724u64, 2793530071884239303u64, 6682616997400869628u64, 2183556611878603887u64, 15662586120087312346u64, 11217427617020813641u64, 1553190491096487459u64, 6304735387851432112u64, 10407758620342516485u64, 15176894045242543510u64, 14905683634900247362u64, 10100238751092381137u64, 5420754629656923748u64, 705519735670536439u64, 9793295161182637981u64, 14202145287119436046u64, 146654890503152315u64, 4682024195942093864u64, 3242565161283638754u64, 7930564333232481137u64, 12186217236017068228u64, 17000743249723264599u64, 7335380351123765565u64, 2827240748300537774u64, 16153640314560107547u64, 11735716164790313608u64, 13734056228011347036u64, 18188291445129067215u64, 4285430719881142650u64, 8757259798139230185u64, 17846161249714921603u64, 13067947420601767440u64, 8235833358291897765u64, 3511522545606540086u64, 5387043107155988493u64, 590673871457609374u64, 14925875833148783915u64, 10219719885873843128u64, 284282506805329106u64, 4684052045342640705u64, 9660285764170764788u64, 14186579979835500391u64, 15610694155489642931u64, 11120709418076880672u64, 6720936860919424149u64, 2284857304564388358u64, 10490913115006887276u64, 15233377424362361855u64, 1474636623804926026u64, 6234696958930763481u64, 16178361609106579004u64, 11706535215248140463u64, 7306199781952008986u64, 2851961734412043657u64, 12229085463316509411u64, 16953397843693241456u64, 3195220067441434565u64, 7973432182840617302u64, 8278700923620460418u64, 3464177731752866065u64, 17798816680404380324u64, 13110814815472245815u64, 4310152537884486493u64, 8728078392784608718u64, 13704874997943502459u64, 18213013024491712744u64, 9707630858549910483u64, 14143712128616820032u64, 241414281116563189u64, 4731397450835853414u64, 14955056402857342732u64, 10194998898151653791u64, 5362321814220069418u64, 619854820462849209u64, 1503817855483314797u64, 6209975379031176446u64, 10466191297540353867u64, 15262558828106308056u64, 6768281431840648882u64, 2241989909157107745u64, 15567826590698013588u64, 11168054230320002311u64];
+static NO_REF_40_0000000004820009: [u64; 256] = CRCu64::crc_table(0x0000000004820009, 40);
+static NO_REF_64_42F0E1EBA9EA3693: [u64; 256] = CRCu64::crc_table(0x42F0E1EBA9EA3693, 64);
+
+static REF_64_92C64265D32139A4: [u64; 256] = CRCu64::crc_reflect_table(0x92C64265D32139A4);
+static REF_64_9A6C9329AC4BC9B5: [u64; 256] = CRCu64::crc_reflect_table(0x9A6C9329AC4BC9B5);
+static REF_64_C96C5795D7870F42: [u64; 256] = CRCu64::crc_reflect_table(0xC96C5795D7870F42);
+static REF_64_D800000000000000: [u64; 256] = CRCu64::crc_reflect_table(0xD800000000000000);
+static REF_64_95AC9329AC4BC9B5: [u64; 256] = CRCu64::crc_reflect_table(0x95AC9329AC4BC9B5);
 
-const REF_64_D800000000000000: [u64; 256] = [0u64, 121597189939003392, 243194379878006784u64, 202661983231672320u64, 486388759756013568u64, 535928355657089024u64, 405323966463344640u64, 292733975779082240u64, 972777519512027136u64, 878201927337246720u64, 1071856711314178048u64, 1103381908705771520u64, 810647932926689280u64, 788129934789836800u64, 585467951558164480u64, 689050742987685888u64, 1945555039024054272u64, 1923037040887201792u64, 1756403854674493440u64, 1859986646104014848u64, 2143713422628356096u64, 2049137830453575680u64, 2206763817411543040u64, 2238289014803136512u64, 1621295865853378560u64, 1670835461754454016u64, 1576259869579673600u64, 1463669878895411200u64, 1170935903116328960u64, 1292533093055332352u64, 1378101485975371776u64, 1337569089329037312u64, 3891110078048108544u64, 4012707267987111936u64, 3846074081774403584u64, 3805541685128069120u64, 3512807709348986880u64, 3562347305250062336u64, 3719973292208029696u64, 3607383301523767296u64, 4287426845256712192u64, 4192851253081931776u64, 4098275660907151360u64, 4129800858298744832u64, 4413527634823086080u64, 4391009636686233600u64, 4476578029606273024u64, 4580160821035794432u64, 3242591731706757120u64, 3220073733569904640u64, 3341670923508908032u64, 3445253714938429440u64, 3152519739159347200u64, 3057944146984566784u64, 2927339757790822400u64, 2958864955182415872u64, 2341871806232657920u64, 2391411402133733376u64, 2585066186110664704u64, 2472476195426402304u64, 2756202971950743552u64, 2877800161889746944u64, 2675138178658074624u64, 2634605782011740160u64, 7782220156096217088u64, 7903817346035220480u64, 8025414535974223872u64, 7984882139327889408u64, 7692148163548807168u64, 7741687759449882624u64, 7611083370256138240u64, 7498493379571875840u64, 7025615418697973760u64, 6931039826523193344u64, 7124694610500124672u64, 7156219807891718144u64, 7439946584416059392u64, 7417428586279206912u64, 7214766603047534592u64, 7318349394477056000u64, 8574853690513424384u64, 8552335692376571904u64, 8385702506163863552u64, 8489285297593384960u64, 8196551321814302720u64, 8101975729639522304u64, 8259601716597489664u64, 8291126913989083136u64, 8827055269646172160u64, 8876594865547247616u64, 8782019273372467200u64, 8669429282688204800u64, 8953156059212546048u64, 9074753249151549440u64, 9160321642071588864u64, 9119789245425254400u64, 6485183463413514240u64, 6606780653352517632u64, 6440147467139809280u64, 6399615070493474816u64, 6683341847017816064u64, 6732881442918891520u64, 6890507429876858880u64, 6777917439192596480u64, 6305039478318694400u64, 6210463886143913984u64, 6115888293969133568u64, 6147413491360727040u64, 5854679515581644800u64, 5832161517444792320u64, 5917729910364831744u64, 6021312701794353152u64, 4683743612465315840u64, 4661225614328463360u64, 4782822804267466752u64, 4886405595696988160u64, 5170132372221329408u64, 5075556780046548992u64, 4944952390852804608u64, 4976477588244398080u64, 5512405943901487104u64, 5561945539802562560u64, 5755600323779493888u64, 5643010333095231488u64, 5350276357316149248u64, 5471873547255152640u64, 5269211564023480320u64, 5228679167377145856u64, 15564440312192434176u64, 15686037502131437568u64, 15807634692070440960u64, 15767102295424106496u64, 16050829071948447744u64, 16100368667849523200u64, 15969764278655778816u64, 15857174287971516416u64, 15384296327097614336u64, 15289720734922833920u64, 15483375518899765248u64, 15514900716291358720u64, 15222166740512276480u64, 15199648742375424000u64, 14996986759143751680u64, 15100569550573273088u64, 14051230837395947520u64, 14028712839259095040u64, 13862079653046386688u64, 13965662444475908096u64, 14249389221000249344u64, 14154813628825468928u64, 14312439615783436288u64, 14343964813175029760u64, 14879893168832118784u64, 14929432764733194240u64, 14834857172558413824u64, 14722267181874151424u64, 14429533206095069184u64, 14551130396034072576u64, 14636698788954112000u64, 14596166392307777536u64, 17149707381026848768u64, 17271304570965852160u64, 17104671384753143808u64, 17064138988106809344u64, 16771405012327727104u64, 16820944608228802560u64, 16978570595186769920u64, 16865980604502507520u64, 16393102643628605440u64, 16298527051453825024u64, 16203951459279044608u64, 16235476656670638080u64, 16519203433194979328u64, 16496685435058126848u64, 16582253827978166272u64, 16685836619407687680u64, 17654110539292344320u64, 17631592541155491840u64, 17753189731094495232u64, 17856772522524016640u64, 17564038546744934400u64, 17469462954570153984u64, 17338858565376409600u64, 17370383762768003072u64, 17906312118425092096u64, 17955851714326167552u64, 18149506498303098880u64, 18036916507618836480u64, 18320643284143177728u64, 18442240474082181120u64, 18239578490850508800u64, 18199046094204174336u64, 12970366926827028480u64, 13091964116766031872u64, 13213561306705035264u64, 13173028910058700800u64, 12880294934279618560u64, 12929834530180694016u64, 12799230140986949632u64, 12686640150302687232u64, 13366683694035632128u64, 13272108101860851712u64, 13465762885837783040u64, 13497288083229376512u64, 13781014859753717760u64, 13758496861616865280u64, 13555834878385192960u64, 13659417669814714368u64, 12610078956637388800u64, 12587560958500536320u64, 12420927772287827968u64, 12524510563717349376u64, 12231776587938267136u64, 12137200995763486720u64, 12294826982721454080u64, 12326352180113047552u64, 11709359031163289600u64, 11758898627064365056u64, 11664323034889584640u64, 11551733044205322240u64, 11835459820729663488u64, 11957057010668666880u64, 12042625403588706304u64, 12002093006942371840u64, 9367487224930631680u64, 9489084414869635072u64, 9322451228656926720u64, 9281918832010592256u64, 9565645608534933504u64, 9615185204436008960u64, 9772811191393976320u64, 9660221200709713920u64, 10340264744442658816u64, 10245689152267878400u64, 10151113560093097984u64, 10182638757484691456u64, 9889904781705609216u64, 9867386783568756736u64, 9952955176488796160u64, 10056537967918317568u64, 11024811887802974208u64, 11002293889666121728u64, 11123891079605125120u64, 11227473871034646528u64, 11511200647558987776u64, 11416625055384207360u64, 11286020666190462976u64, 11317545863582056448u64, 10700552714632298496u64, 10750092310533373952u64, 10943747094510305280u64, 10831157103826042880u64, 10538423128046960640u64, 10660020317985964032u64, 10457358334754291712u64, 10416825938107957248u64];
-const REF_64_95AC9329AC4BC9B5: [u64; 256] = [0u64, 8851949072701294969u64, 17703898145402589938u64, 10333669153493130123u64, 13851072938616403599u64, 13465927519055396854u64, 3857338458010461309u64, 5715195658523061508u64, 12333367839138578037u64, 15127763206205961996u64, 6816212484437830791u64, 2612226237385041406u64, 7714676916020922618u64, 1281407202545942915u64, 11430391317046123016u64, 16463076249205199729u64, 9009731685717012353u64, 563108230357313272u64, 9851657908567506291u64, 17465080730062222346u64, 13632424968875661582u64, 14404880506683019383u64, 5224452474770082812u64, 3627802401766982277u64, 15429353832041845236u64, 12463821128841762957u64, 2562814405091885830u64, 6433535930597116543u64, 1592294032496338811u64, 7836410910743637506u64, 16404387395731993993u64, 11056451039949864176u64, 18019463371434024706u64, 9280105458721969787u64, 1126216460714626544u64, 8464919223366468745u64, 4190910634541279629u64, 4679640014836523252u64, 14959263154764675967u64, 13060872525739979270u64, 5852729821509460343u64, 3161916214005835790u64, 11856275032257016709u64, 16019730051968187132u64, 10448904949540165624u64, 16994763621833383553u64, 7255604803533964554u64, 2191395843288271987u64, 9734813498046853251u64, 18285020776702097914u64, 8262382231073956465u64, 608425843627928328u64, 5125628810183771660u64, 4465764294926438261u64, 12867071861194233086u64, 14432195567501024647u64, 3184588064992677622u64, 6262709589572306831u64, 15672821821487275012u64, 11770576130456212861u64, 17008134862606432377u64, 10867599606483677440u64, 1853769023980628619u64, 7161174014982448114u64, 16103423924954344815u64, 11935289383220651030u64, 3083341959784644509u64, 5769757520242456292u64, 2252432921429253088u64, 7321251034957484697u64, 16929838446732937490u64, 10388307452745547883u64, 8381821269082559258u64, 1047727658635319907u64, 9359280029673046504u64, 18102965619612993681u64, 13000435797616977301u64, 14894146905688698092u64, 4745161141923116903u64, 4252033715651608094u64, 11705459643018920686u64, 15612384854998895511u64, 6323832428011671580u64, 3250108949404244325u64, 7082685524280996961u64, 1770671381070249240u64, 10951102161764411027u64, 17087309740654948330u64, 674072313427442843u64, 8323419547594995170u64, 18224423522563763817u64, 9669888565606754064u64, 14511209607067929108u64, 12950765422787986285u64, 4382791686576543974u64, 5047054248884015519u64, 2696289253709771373u64, 6895947823530343188u64, 15049839570318909599u64, 12250835051042597350u64, 16524764462147912930u64, 11496477575961038235u64, 1216851687255856656u64, 7654800921679748969u64, 10251257620367543320u64, 17625884659327141217u64, 8931528589852876522u64, 84259039178430355u64, 5655163293556783767u64, 3792978414742418414u64, 13532134484260726885u64, 13912670750543257884u64, 6369176129985355244u64, 2502782282785952917u64, 12525419179144613662u64, 15495561035627234919u64, 10978437246791527267u64, 16321975555527844378u64, 7920669638525335953u64, 1671873238255513832u64, 17531166746306175897u64, 9913345878835194592u64, 503231997654823275u64, 8945175932061546514u64, 3707538047961257238u64, 5308515798192249967u64, 14322348029964896228u64, 13554501644362141341u64, 10785157014839085493u64, 17254666630495879372u64, 6925536469308201799u64, 1928669229005230654u64, 6166683919569289018u64, 3408106242218915395u64, 11539515040484912584u64, 15779741191858611377u64, 4504865842858506176u64, 4925828954283753145u64, 14642502069914969394u64, 12820884771576065099u64, 18355716529793696079u64, 9540007361421969462u64, 796147016248169405u64, 8202193697865996996u64, 16763642538165118516u64, 10555343349626187597u64, 2095455317270639814u64, 7479631577382337983u64, 2926364910754730171u64, 5928137516128508354u64, 15937228569359352393u64, 12102324735718361904u64, 4867406749023426625u64, 4131191115536978232u64, 13131477498808912563u64, 14763945261529023434u64, 9490322283846233806u64, 17972763431062038455u64, 8504067431303216188u64, 926884511990314309u64, 8051711962477172407u64, 1541670979892322254u64, 11100683476643087429u64, 16201132341218348348u64, 12647664856023343160u64, 15374718365700663617u64, 6500217898808488650u64, 2372580570961558451u64, 14165371048561993922u64, 13712881572587659707u64, 3541342762140498480u64, 5475551080882205513u64, 337036156713721421u64, 9112211761281881908u64, 17374189211922025663u64, 10071726351451997638u64, 1348144626854885686u64, 7524919785159454799u64, 16646839095189990340u64, 11375251796044276413u64, 15171913658969673657u64, 12129609824107054784u64, 2827581646778391883u64, 6766067242130363442u64, 13374985906044110659u64, 14070668113165684282u64, 5489218623395763633u64, 3960334819262667976u64, 8765583373153087948u64, 251615998827411637u64, 10094108497768031038u64, 17783882574922426951u64, 5392578507419542746u64, 3462768234654100899u64, 13791895647060686376u64, 14249064643987996497u64, 10011129131143811669u64, 17309264314385947436u64, 9177858264896848039u64, 398073508124084702u64, 16284634862666717871u64, 11179858319785628630u64, 1463182455377365085u64, 7968614284679676196u64, 2433703374511713312u64, 6565738749404456281u64, 15309601843359497938u64, 12587227855704700843u64, 4025855981238586203u64, 5550341738321543714u64, 14010231419946703273u64, 13309869690798280912u64, 17863057179705753044u64, 10177610780853122221u64, 168518078356860710u64, 8687094605961012831u64, 11310326587113567534u64, 16586241563491499095u64, 7585956829484836828u64, 1413790823389195941u64, 6687492953022055329u64, 2744609311697881816u64, 12213303662187237715u64, 15250927976100943914u64, 12738352259970710488u64, 14564578711588090529u64, 5005564565571905834u64, 4588929132448424019u64, 8142317431333358935u64, 731591227688682542u64, 9606093343850471333u64, 18417404465172059868u64, 2012927990619293101u64, 7005115709973351636u64, 17176652871151048543u64, 10702745209522052646u64, 15841339277050671906u64, 11605722277885901403u64, 3343746476511027664u64, 6106651831093618857u64, 14830152191845028953u64, 13193075276920315168u64, 4071158715666679467u64, 4803046671925235666u64, 1006463995309646550u64, 8588326435575524271u64, 17890351864123093028u64, 9412308762883553629u64, 7415076095922514476u64, 2035579357833339733u64, 10617031596384499934u64, 16829728831969243559u64, 12024401134718426275u64, 15854695815076877786u64, 6012200567359213137u64, 3006100283679606568u64];
+static NO_REF_40_0000000004820009_SLICING: [[u64; 256]; 8] = CRCu64::crc_slicing_tables(&NO_REF_40_0000000004820009, 40);
+static NO_REF_64_42F0E1EBA9EA3693_SLICING: [[u64; 256]; 8] = CRCu64::crc_slicing_tables(&NO_REF_64_42F0E1EBA9EA3693, 64);
+
+static REF_64_92C64265D32139A4_SLICING: [[u64; 256]; 8] = CRCu64::crc_reflect_slicing_tables(&REF_64_92C64265D32139A4);
+static REF_64_9A6C9329AC4BC9B5_SLICING: [[u64; 256]; 8] = CRCu64::crc_reflect_slicing_tables(&REF_64_9A6C9329AC4BC9B5);
+static REF_64_C96C5795D7870F42_SLICING: [[u64; 256]; 8] = CRCu64::crc_reflect_slicing_tables(&REF_64_C96C5795D7870F42);
+static REF_64_D800000000000000_SLICING: [[u64; 256]; 8] = CRCu64::crc_reflect_slicing_tables(&REF_64_D800000000000000);
+static REF_64_95AC9329AC4BC9B5_SLICING: [[u64; 256]; 8] = CRCu64::crc_reflect_slicing_tables(&REF_64_95AC9329AC4BC9B5);
 
 
 impl CRCu64 {
-    pub fn crc40gsm() -> CRCu64 {
+    pub const fn crc40gsm() -> CRCu64 {
 //         Self::create_crc(0x0000000004820009u64, 40, 0x0000000000000000, 0x000000FFFFFFFFFF, false)
 
-        let lookup_table = NO_REF_40_0000000004820009;
-        Self::create_crc_with_exists_lookup_table(lookup_table, 40, 0x0000000000000000, 0x000000FFFFFFFFFF, false)
+        let lookup_table = &NO_REF_40_0000000004820009;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &NO_REF_40_0000000004820009_SLICING, 40, 0x0000000000000000, 0x000000FFFFFFFFFF, false)
     }
 
-    pub fn crc64() -> CRCu64 {
+    pub const fn crc64() -> CRCu64 {
         // Self::create_crc(0x42F0E1EBA9EA3693, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, false)
 
-        let lookup_table = NO_REF_64_42F0E1EBA9EA3693;
-        Self::create_crc_with_exists_lookup_table(lookup_table, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, false)
+        let lookup_table = &NO_REF_64_42F0E1EBA9EA3693;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &NO_REF_64_42F0E1EBA9EA3693_SLICING, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, false)
+    }
+
+    #[inline]
+    pub const fn crc64we() -> CRCu64 {
+        Self::crc64()
     }
 
-    pub fn crc64iso() -> CRCu64 {
+    pub const fn crc64iso() -> CRCu64 {
         // Self::create_crc(0xD800000000000000u64, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
 
-        let lookup_table = REF_64_D800000000000000;
-        Self::create_crc_with_exists_lookup_table(lookup_table, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
+        let lookup_table = &REF_64_D800000000000000;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &REF_64_D800000000000000_SLICING, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
+    }
+
+    #[inline]
+    pub const fn crc64go_iso() -> CRCu64 {
+        Self::crc64iso()
     }
 
-    pub fn crc64jones() -> CRCu64 {
+    pub const fn crc64jones() -> CRCu64 {
 //         Self::create_crc(0x95AC9329AC4BC9B5u64, 64, 0x0000000000000000, 0x0000000000000000, true)
 
-        let lookup_table = REF_64_95AC9329AC4BC9B5;
-        Self::create_crc_with_exists_lookup_table(lookup_table, 64, 0x0000000000000000, 0x0000000000000000, true)
+        let lookup_table = &REF_64_95AC9329AC4BC9B5;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &REF_64_95AC9329AC4BC9B5_SLICING, 64, 0x0000000000000000, 0x0000000000000000, true)
+    }
+
+    #[inline]
+    pub const fn crc64redis() -> CRCu64 {
+        Self::crc64jones()
+    }
+
+    pub const fn crc64ecma182() -> CRCu64 {
+        // Self::create_crc(0x42F0E1EBA9EA3693, 64, 0x0000000000000000, 0x0000000000000000, false)
+
+        let lookup_table = &NO_REF_64_42F0E1EBA9EA3693;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &NO_REF_64_42F0E1EBA9EA3693_SLICING, 64, 0x0000000000000000, 0x0000000000000000, false)
+    }
+
+    pub const fn crc64ms() -> CRCu64 {
+        // Self::create_crc(0x92C64265D32139A4, 64, 0xFFFFFFFFFFFFFFFF, 0x0000000000000000, true)
+
+        let lookup_table = &REF_64_92C64265D32139A4;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &REF_64_92C64265D32139A4_SLICING, 64, 0xFFFFFFFFFFFFFFFF, 0x0000000000000000, true)
+    }
+
+    pub const fn crc64nvme() -> CRCu64 {
+        // Self::create_crc(0x9A6C9329AC4BC9B5, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
+
+        let lookup_table = &REF_64_9A6C9329AC4BC9B5;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &REF_64_9A6C9329AC4BC9B5_SLICING, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
+    }
+
+    pub const fn crc64xz() -> CRCu64 {
+        // Self::create_crc(0xC96C5795D7870F42, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
+
+        let lookup_table = &REF_64_C96C5795D7870F42;
+        Self::create_crc_with_exists_slicing_tables(lookup_table, &REF_64_C96C5795D7870F42_SLICING, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true)
     }
 }
 
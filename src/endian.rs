@@ -0,0 +1,10 @@
+//! The byte orders of CRC values.
+
+/// The byte order of a CRC value which is encoded in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// The most significant byte first, like `get_crc_vec_be`.
+    Big,
+    /// The least significant byte first, like `get_crc_vec_le`.
+    Little,
+}
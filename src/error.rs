@@ -0,0 +1,70 @@
+//! Errors of creating CRC instances with invalid parameters.
+
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// The reasons why a CRC instance cannot be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CRCError {
+    /// The length of bits is zero or larger than the maximum length of bits (the second value) which the CRC type supports.
+    InvalidWidth(u8, u8),
+    /// The expression has bits which are out of the length of bits.
+    PolynomialOutOfRange,
+    /// The expression does not have the lowest term (`x^0`), which is the highest bit if the expression is reflected.
+    EvenPolynomial,
+    /// The initial value has bits which are out of the length of bits.
+    InitialValueTooWide,
+    /// The final xor value has bits which are out of the length of bits.
+    FinalXorTooWide,
+}
+
+impl Display for CRCError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            CRCError::InvalidWidth(bits, max_bits) => f.write_fmt(format_args!("the length of bits {} is not in 1..={}", bits, max_bits)),
+            CRCError::PolynomialOutOfRange => f.write_str("the expression is wider than the length of bits"),
+            CRCError::EvenPolynomial => f.write_str("the expression does not have the lowest term"),
+            CRCError::InitialValueTooWide => f.write_str("the initial value is wider than the length of bits"),
+            CRCError::FinalXorTooWide => f.write_str("the final xor value is wider than the length of bits"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CRCError {}
+
+/// Check the parameters of creating a CRC instance whose type supports at most `max_bits` bits. The expression is reflected if `reflect` is `true`.
+pub(crate) const fn check_parameters(poly: u128, bits: u8, max_bits: u8, initial: u128, final_xor: u128, reflect: bool) -> Result<(), CRCError> {
+    if bits == 0 || bits > max_bits {
+        return Err(CRCError::InvalidWidth(bits, max_bits));
+    }
+
+    let mask = u128::MAX >> (128 - bits);
+
+    if poly & !mask != 0 {
+        return Err(CRCError::PolynomialOutOfRange);
+    }
+
+    let lowest_term = if reflect {
+        1 << (bits - 1)
+    } else {
+        1
+    };
+
+    if poly & lowest_term == 0 {
+        return Err(CRCError::EvenPolynomial);
+    }
+
+    if initial & !mask != 0 {
+        return Err(CRCError::InitialValueTooWide);
+    }
+
+    if final_xor & !mask != 0 {
+        return Err(CRCError::FinalXorTooWide);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+//! Use CRCs as deterministic hash functions, e.g. for `HashMap`s.
+
+use core::hash::{BuildHasher, Hasher};
+
+/// A `BuildHasher` which creates hashers by cloning a CRC instance, so the algorithm is chosen by the instance, e.g. `CRCBuildHasher::new(CRCu32::crc32c())`.
+///
+/// CRCs are fast and deterministic, but they are not resistant to HashDoS attacks, so do not use them to hash untrusted keys.
+#[derive(Debug, Clone)]
+pub struct CRCBuildHasher<T> {
+    crc: T,
+}
+
+impl<T> CRCBuildHasher<T> {
+    /// Create a `CRCBuildHasher` instance by providing a CRC instance which has not digested any data.
+    #[inline]
+    pub const fn new(crc: T) -> CRCBuildHasher<T> {
+        CRCBuildHasher {
+            crc,
+        }
+    }
+}
+
+impl<T: Hasher + Clone> BuildHasher for CRCBuildHasher<T> {
+    type Hasher = T;
+
+    #[inline]
+    fn build_hasher(&self) -> T {
+        self.crc.clone()
+    }
+}
@@ -0,0 +1,272 @@
+//! Compute CRC values of data which flows through `std::io::Read` and `std::io::Write`, or their asynchronous counterparts of the `futures-io` crate and the `tokio` crate.
+
+use core::hash::Hasher;
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+use core::pin::Pin;
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+use core::task::{Context, Poll};
+
+use std::io::{self, Read, Write};
+
+use CRC;
+
+/// A reader which updates a CRC instance with the bytes read from the inner reader. The CRC instance can be `CRC` or any `CRCu*` type.
+///
+/// The inner reader can also be a `futures_io::AsyncRead` (with the `futures-io` feature) or a `tokio::io::AsyncRead` (with the `tokio` feature).
+#[derive(Debug, Clone)]
+pub struct CRCReader<R, C = CRC> {
+    inner: R,
+    crc: C,
+    length: u64,
+}
+
+impl<R, C: Hasher> CRCReader<R, C> {
+    /// Create a `CRCReader` instance by providing a reader and a CRC instance.
+    #[inline]
+    pub fn new(inner: R, crc: C) -> CRCReader<R, C> {
+        CRCReader {
+            inner,
+            crc,
+            length: 0,
+        }
+    }
+}
+
+impl<R, C> CRCReader<R, C> {
+    /// Get the CRC instance, which has digested all bytes read so far.
+    #[inline]
+    pub fn crc(&self) -> &C {
+        &self.crc
+    }
+
+    /// Get the mutable CRC instance.
+    #[inline]
+    pub fn crc_mut(&mut self) -> &mut C {
+        &mut self.crc
+    }
+
+    /// Get the number of bytes read so far.
+    #[inline]
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Get the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get the mutable inner reader. Bytes read from it directly are not digested.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap this `CRCReader` into the inner reader and the CRC instance.
+    #[inline]
+    pub fn into_parts(self) -> (R, C) {
+        (self.inner, self.crc)
+    }
+
+    /// Project the pinned reader to its pinned inner reader, the CRC instance and the length.
+    #[cfg(any(feature = "futures-io", feature = "tokio"))]
+    #[inline]
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut R>, &mut C, &mut u64) {
+        // the inner reader is never moved out of a pinned `CRCReader`, and the CRC instance and the length are not pinned
+        unsafe {
+            let this = self.get_unchecked_mut();
+
+            (Pin::new_unchecked(&mut this.inner), &mut this.crc, &mut this.length)
+        }
+    }
+}
+
+impl<R: Read, C: Hasher> Read for CRCReader<R, C> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let c = self.inner.read(buf)?;
+
+        self.crc.write(&buf[..c]);
+        self.length += c as u64;
+
+        Ok(c)
+    }
+}
+
+/// A writer which updates a CRC instance with the bytes written to the inner writer. The CRC instance can be `CRC` or any `CRCu*` type.
+///
+/// The inner writer can also be a `futures_io::AsyncWrite` (with the `futures-io` feature) or a `tokio::io::AsyncWrite` (with the `tokio` feature).
+#[derive(Debug, Clone)]
+pub struct CRCWriter<W, C = CRC> {
+    inner: W,
+    crc: C,
+    length: u64,
+}
+
+impl<W, C: Hasher> CRCWriter<W, C> {
+    /// Create a `CRCWriter` instance by providing a writer and a CRC instance.
+    #[inline]
+    pub fn new(inner: W, crc: C) -> CRCWriter<W, C> {
+        CRCWriter {
+            inner,
+            crc,
+            length: 0,
+        }
+    }
+}
+
+impl<W, C> CRCWriter<W, C> {
+    /// Get the CRC instance, which has digested all bytes written so far.
+    #[inline]
+    pub fn crc(&self) -> &C {
+        &self.crc
+    }
+
+    /// Get the mutable CRC instance.
+    #[inline]
+    pub fn crc_mut(&mut self) -> &mut C {
+        &mut self.crc
+    }
+
+    /// Get the number of bytes written so far.
+    #[inline]
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Get the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get the mutable inner writer. Bytes written to it directly are not digested.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this `CRCWriter` into the inner writer and the CRC instance.
+    #[inline]
+    pub fn into_parts(self) -> (W, C) {
+        (self.inner, self.crc)
+    }
+
+    /// Project the pinned writer to its pinned inner writer, the CRC instance and the length.
+    #[cfg(any(feature = "futures-io", feature = "tokio"))]
+    #[inline]
+    fn project(self: Pin<&mut Self>) -> (Pin<&mut W>, &mut C, &mut u64) {
+        // the inner writer is never moved out of a pinned `CRCWriter`, and the CRC instance and the length are not pinned
+        unsafe {
+            let this = self.get_unchecked_mut();
+
+            (Pin::new_unchecked(&mut this.inner), &mut this.crc, &mut this.length)
+        }
+    }
+}
+
+impl<W: Write, C: Hasher> Write for CRCWriter<W, C> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let c = self.inner.write(buf)?;
+
+        self.crc.write(&buf[..c]);
+        self.length += c as u64;
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<R: futures_io::AsyncRead, C: Hasher> futures_io::AsyncRead for CRCReader<R, C> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let (inner, crc, length) = self.project();
+
+        let result = inner.poll_read(cx, buf);
+
+        if let Poll::Ready(Ok(c)) = result {
+            crc.write(&buf[..c]);
+            *length += c as u64;
+        }
+
+        result
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<W: futures_io::AsyncWrite, C: Hasher> futures_io::AsyncWrite for CRCWriter<W, C> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let (inner, crc, length) = self.project();
+
+        let result = inner.poll_write(cx, buf);
+
+        if let Poll::Ready(Ok(c)) = result {
+            crc.write(&buf[..c]);
+            *length += c as u64;
+        }
+
+        result
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.project().0.poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.project().0.poll_close(cx)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead, C: Hasher> tokio::io::AsyncRead for CRCReader<R, C> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut tokio::io::ReadBuf) -> Poll<io::Result<()>> {
+        let (inner, crc, length) = self.project();
+
+        let filled = buf.filled().len();
+
+        let result = inner.poll_read(cx, buf);
+
+        if let Poll::Ready(Ok(())) = result {
+            let data = &buf.filled()[filled..];
+
+            crc.write(data);
+            *length += data.len() as u64;
+        }
+
+        result
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite, C: Hasher> tokio::io::AsyncWrite for CRCWriter<W, C> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let (inner, crc, length) = self.project();
+
+        let result = inner.poll_write(cx, buf);
+
+        if let Poll::Ready(Ok(c)) = result {
+            crc.write(&buf[..c]);
+            *length += c as u64;
+        }
+
+        result
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.project().0.poll_flush(cx)
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        self.project().0.poll_shutdown(cx)
+    }
+}
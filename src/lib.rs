@@ -21,14 +21,17 @@ assert_eq!([71, 245, 138].to_vec(), crc24.get_crc_vec_be());
 assert_eq!("0x47F58A", &crc24.to_string());
 ```
 
-To simplify the usage, there are several common versions of CRC whose computing functions are already built-in.
+To simplify the usage, there are several common versions of CRC whose computing functions are already built-in. They cover every algorithm in the [CRC RevEng catalogue](https://reveng.sourceforge.io/crc-catalogue/all.htm).
 
  * crc3gsm
+ * crc3rohc
  * crc4itu
  * crc4interlaken
  * crc5epc
+   * Its initial value is `0x00`, so it is not CRC-5/EPC-C1G2 in the CRC RevEng catalogue, which is `crc5epc_c1g2`.
  * crc5itu
  * crc5usb
+ * crc5epc_c1g2
  * crc6cdma2000_a
  * crc6cdma2000_b
  * crc6darc
@@ -36,6 +39,7 @@ To simplify the usage, there are several common versions of CRC whose computing
  * crc6itu
  * crc7
  * crc7umts
+ * crc7rohc
  * crc8
  * crc8cdma2000
  * crc8darc
@@ -46,11 +50,23 @@ To simplify the usage, there are several common versions of CRC whose computing
  * crc8maxim
  * crc8rohc
  * crc8wcdma
+ * crc8autosar
+ * crc8bluetooth
+ * crc8gsm_a
+ * crc8gsm_b
+ * crc8hitag
+ * crc8lte
+ * crc8mifare_mad
+ * crc8nrsc5
+ * crc8opensafety
+ * crc8sae_j1850
  * crc10
  * crc10cdma2000
  * crc10gsm
  * crc11
+ * crc11umts
  * crc12
+ * crc12umts
  * crc12cdma2000
  * crc12gsm
  * crc13bbc
@@ -79,7 +95,17 @@ To simplify the usage, there are several common versions of CRC whose computing
  * crc16kermit
  * crc16modbus
  * crc16_x25
+ * crc16ibm_sdlc
+   * An alias of `crc16_x25`.
  * crc16xmodem
+ * crc16cms
+ * crc16gsm
+ * crc16lj1200
+ * crc16m17
+ * crc16nrsc5
+ * crc16opensafety_a
+ * crc16opensafety_b
+ * crc16profibus
  * crc17can
  * crc21can
  * crc24
@@ -89,23 +115,41 @@ To simplify the usage, there are several common versions of CRC whose computing
  * crc24lte_a
  * crc24lte_b
  * crc24os9
+ * crc24interlaken
  * crc30cdma
+ * crc31philips
  * crc32
    * It also called `crc32b` in `mhash`.
  * crc32mhash
    * `mhash` is a common library which has two weird versions of CRC32 called `crc32` and `crc32b`. `crc32` and `crc32mhash` in this module are `crc32b` and `crc32` in mhash respectively.
  * crc32bzip2
  * crc32c
+ * crc32iscsi
+   * An alias of `crc32c`.
  * crc32d
  * crc32mpeg2
  * crc32posix
  * crc32q
  * crc32jamcrc
  * crc32xfer
+ * crc32autosar
+ * crc32cd_rom_edc
+ * crc32mef
  * crc40gsm
  * crc64
+ * crc64we
+   * An alias of `crc64`.
  * crc64iso
+ * crc64go_iso
+   * An alias of `crc64iso`.
  * crc64jones
+ * crc64redis
+   * An alias of `crc64jones`.
+ * crc64ecma182
+ * crc64ms
+ * crc64nvme
+ * crc64xz
+ * crc82darc
 
 For instance,
 
@@ -130,6 +174,21 @@ After getting a CRC value, you can still use the `digest` method to continue com
 #[cfg(feature = "default")]
 #[macro_use] extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
+#[cfg(feature = "digest")]
+extern crate digest;
+
+#[cfg(feature = "futures-io")]
+extern crate futures_io;
+
+#[cfg(feature = "tokio")]
+extern crate tokio;
+
 #[cfg(feature = "default")]
 #[macro_use] extern crate debug_helper;
 
@@ -139,24 +198,60 @@ use alloc::vec::Vec;
 #[cfg(feature = "default")]
 use alloc::fmt::{self, Formatter, Display};
 
+use core::hash::Hasher;
+
 mod crc_u8;
 mod crc_u16;
 mod crc_u32;
 mod crc_u64;
+mod crc_u128;
+mod sse42;
+#[cfg(target_arch = "x86_64")]
+mod pclmul;
+mod table;
+mod parameters;
+mod catalogue;
+mod error;
+mod endian;
+mod hasher;
+#[cfg(feature = "default")]
+mod reveng;
+#[cfg(feature = "default")]
+mod correction;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "digest")]
+pub mod rustcrypto;
 
 
 pub use crc_u8::CRCu8;
 pub use crc_u16::CRCu16;
 pub use crc_u32::CRCu32;
 pub use crc_u64::CRCu64;
+pub use crc_u128::CRCu128;
+pub use parameters::{CRCParameters, CRCParametersBuilder};
+pub use error::CRCError;
+pub use endian::Endian;
+pub use catalogue::CRCMatch;
+pub use hasher::CRCBuildHasher;
+#[cfg(feature = "default")]
+pub use correction::{CRCCorrector, Correction, CorrectionError};
+#[cfg(feature = "std")]
+pub use io::{CRCReader, CRCWriter};
 
 /// This struct can help you compute a CRC value.
 #[cfg_attr(feature = "default", derive(Debug))]
+// without `alloc`, the lookup tables are owned by the instances and cannot be boxed
+#[cfg_attr(not(feature = "default"), allow(clippy::large_enum_variant))]
+#[derive(Clone)]
 pub enum CRC {
     CRCu8(CRCu8),
     CRCu16(CRCu16),
     CRCu32(CRCu32),
     CRCu64(CRCu64),
+    CRCu128(CRCu128),
 }
 
 #[cfg(feature = "default")]
@@ -168,14 +263,63 @@ impl Display for CRC {
             CRC::CRCu16(crc) => Display::fmt(crc, f),
             CRC::CRCu32(crc) => Display::fmt(crc, f),
             CRC::CRCu64(crc) => Display::fmt(crc, f),
+            CRC::CRCu128(crc) => Display::fmt(crc, f),
         }
     }
 }
 
+/// The `finish` method returns the same value as `get_crc`.
+impl Hasher for CRC {
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self {
+            CRC::CRCu8(crc) => crc.finish(),
+            CRC::CRCu16(crc) => crc.finish(),
+            CRC::CRCu32(crc) => crc.finish(),
+            CRC::CRCu64(crc) => crc.finish(),
+            CRC::CRCu128(crc) => crc.finish(),
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.digest(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for CRC {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.digest(buf);
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Panic if the length of bits is not supported by any CRC type.
+#[inline]
+fn check_width(bits: u8) {
+    if bits == 0 || bits > 128 {
+        panic!("{}", CRCError::InvalidWidth(bits, 128));
+    }
+}
+
 impl CRC {
-    /// Create a CRC instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
+    /// Create a CRC instance by providing the length of bits, expression, reflection, an initial value and a final xor value. For a CRC wider than 64 bits whose values do not fit in `u64`, use `create_crc_u128` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is `0` or larger than `128`. Use `try_create_crc` to get a `CRCError` instead.
     #[inline]
     pub fn create_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> CRC {
+        check_width(bits);
+
         if bits <= 8 {
             Self::create_crc_u8(poly as u8, bits, initial as u8, final_xor as u8, reflect)
         } else if bits <= 16 {
@@ -185,10 +329,106 @@ impl CRC {
         } else if bits <= 64 {
             Self::create_crc_u64(poly, bits, initial, final_xor, reflect)
         } else {
-            unimplemented!()
+            Self::create_crc_u128(poly as u128, bits, initial as u128, final_xor as u128, reflect)
         }
     }
 
+    /// Create a CRC instance by providing the length of bits, expression, reflection of the input and the output, an initial value and a final xor value. The expression is reflected if `reflect_in` is `true`, like the one which `create_crc` accepts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is `0` or larger than `128`. Use `try_create_crc_with_reflection` to get a `CRCError` instead.
+    #[inline]
+    pub fn create_crc_with_reflection(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect_in: bool, reflect_out: bool) -> CRC {
+        check_width(bits);
+
+        if bits <= 8 {
+            CRC::CRCu8(CRCu8::create_crc_with_reflection(poly as u8, bits, initial as u8, final_xor as u8, reflect_in, reflect_out))
+        } else if bits <= 16 {
+            CRC::CRCu16(CRCu16::create_crc_with_reflection(poly as u16, bits, initial as u16, final_xor as u16, reflect_in, reflect_out))
+        } else if bits <= 32 {
+            CRC::CRCu32(CRCu32::create_crc_with_reflection(poly as u32, bits, initial as u32, final_xor as u32, reflect_in, reflect_out))
+        } else if bits <= 64 {
+            CRC::CRCu64(CRCu64::create_crc_with_reflection(poly, bits, initial, final_xor, reflect_in, reflect_out))
+        } else {
+            CRC::CRCu128(CRCu128::create_crc_with_reflection(poly as u128, bits, initial as u128, final_xor as u128, reflect_in, reflect_out))
+        }
+    }
+
+    /// Create a CRC instance by providing the parameters of an algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parameters.width` is `0` or larger than `128`. Use `try_create_crc_with_parameters` to get a `CRCError` instead.
+    #[inline]
+    pub fn create_crc_with_parameters(parameters: &CRCParameters) -> CRC {
+        check_width(parameters.width);
+
+        if parameters.width <= 8 {
+            CRC::CRCu8(CRCu8::create_crc_with_parameters(parameters))
+        } else if parameters.width <= 16 {
+            CRC::CRCu16(CRCu16::create_crc_with_parameters(parameters))
+        } else if parameters.width <= 32 {
+            CRC::CRCu32(CRCu32::create_crc_with_parameters(parameters))
+        } else if parameters.width <= 64 {
+            CRC::CRCu64(CRCu64::create_crc_with_parameters(parameters))
+        } else {
+            CRC::CRCu128(CRCu128::create_crc_with_parameters(parameters))
+        }
+    }
+
+    /// Find the algorithms in the CRC RevEng catalogue which produce the given CRC values of the given messages, by providing `(message, CRC value)` samples. A CRC value which was read from bytes in the wrong byte order (i.e. byte-swapped) is also matched, and the byte order is reported.
+    #[cfg(feature = "default")]
+    #[inline]
+    pub fn identify(samples: &[(&[u8], u128)]) -> Vec<CRCMatch> {
+        catalogue::identify(samples)
+    }
+
+    /// Find the algorithms which produce the given CRC values of the given messages, by providing `(message, CRC value)` samples and the length of bits if it is known. The expression is solved over GF(2) from the differences of the samples of the same length, so at least two different samples of the same length are needed, and samples of different lengths are needed to tell the initial value from the final xor value. If some bits of the initial value still cannot be determined, all of their values are tried when there are at most 8 of them; otherwise only the solutions whose undetermined bits are all zeros or all ones are returned.
+    #[cfg(feature = "default")]
+    #[inline]
+    pub fn reverse_engineer(samples: &[(&[u8], u128)], bits: Option<u8>) -> Vec<CRC> {
+        reveng::search(samples, bits)
+    }
+
+    /// Create a CRC instance like `create_crc`, but return an error instead of panicking or creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect: bool) -> Result<CRC, CRCError> {
+        error::check_parameters(poly as u128, bits, 128, initial as u128, final_xor as u128, reflect)?;
+
+        Ok(Self::create_crc(poly, bits, initial, final_xor, reflect))
+    }
+
+    /// Create a CRC instance like `create_crc_with_reflection`, but return an error instead of panicking or creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc_with_reflection(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect_in: bool, reflect_out: bool) -> Result<CRC, CRCError> {
+        error::check_parameters(poly as u128, bits, 128, initial as u128, final_xor as u128, reflect_in)?;
+
+        Ok(Self::create_crc_with_reflection(poly, bits, initial, final_xor, reflect_in, reflect_out))
+    }
+
+    /// Create a CRC instance like `create_crc_with_parameters`, but return an error instead of panicking or creating a broken instance if the parameters are invalid.
+    #[inline]
+    pub fn try_create_crc_with_parameters(parameters: &CRCParameters) -> Result<CRC, CRCError> {
+        error::check_parameters(parameters.poly, parameters.width, 128, parameters.init, parameters.xorout, false)?;
+
+        Ok(Self::create_crc_with_parameters(parameters))
+    }
+
+    /// Create a CRC instance of a built-in algorithm by providing its name in the CRC RevEng catalogue (like `"CRC-32/ISO-HDLC"`), one of its aliases (like `"CRC-32"` or `"PKZIP"`) or the name of its built-in function (like `"crc32"`). The name is case-insensitive.
+    pub fn from_name(name: &str) -> Option<CRC> {
+        if let Some((_, create)) = catalogue::find(name) {
+            return Some(create());
+        }
+
+        catalogue::FUNCTIONS.iter().find(|(function, _)| function.eq_ignore_ascii_case(name)).map(|(_, create)| create())
+    }
+
+    /// Iterate over the names of all built-in functions (including the aliases) along with their CRC instances. Use `CRCParameters::catalogue` to iterate over the algorithms by their names in the CRC RevEng catalogue.
+    pub fn built_in_functions() -> impl Iterator<Item = (&'static str, CRC)> {
+        catalogue::FUNCTIONS.iter().map(|(function, create)| (*function, create()))
+    }
+
     /// Create a CRC instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
     #[inline]
     pub fn create_crc_u8(poly: u8, bits: u8, initial: u8, final_xor: u8, reflect: bool) -> CRC {
@@ -221,6 +461,14 @@ impl CRC {
         CRC::CRCu64(crc)
     }
 
+    /// Create a CRC instance by providing the length of bits, expression, reflection, an initial value and a final xor value.
+    #[inline]
+    pub fn create_crc_u128(poly: u128, bits: u8, initial: u128, final_xor: u128, reflect: bool) -> CRC {
+        let crc = CRCu128::create_crc(poly, bits, initial, final_xor, reflect);
+
+        CRC::CRCu128(crc)
+    }
+
     /// Digest some data.
     #[inline]
     pub fn digest<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
@@ -229,6 +477,68 @@ impl CRC {
             CRC::CRCu16(crc) => crc.digest(data),
             CRC::CRCu32(crc) => crc.digest(data),
             CRC::CRCu64(crc) => crc.digest(data),
+            CRC::CRCu128(crc) => crc.digest(data),
+        }
+    }
+
+    /// Digest some data by using multiple threads. Large data is split into chunks whose CRC values are computed in parallel and then combined, so the result is the same as the one of the `digest` method.
+    #[inline]
+    #[cfg(feature = "rayon")]
+    pub fn digest_parallel<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
+        match self {
+            CRC::CRCu8(crc) => crc.digest_parallel(data),
+            CRC::CRCu16(crc) => crc.digest_parallel(data),
+            CRC::CRCu32(crc) => crc.digest_parallel(data),
+            CRC::CRCu64(crc) => crc.digest_parallel(data),
+            CRC::CRCu128(crc) => crc.digest_parallel(data),
+        }
+    }
+
+    /// Digest the first `bit_len` bits of some data, taking the bits of each byte from the most significant one (MSB-first).
+    #[inline]
+    pub fn digest_bits(&mut self, data: &[u8], bit_len: usize) {
+        match self {
+            CRC::CRCu8(crc) => crc.digest_bits(data, bit_len),
+            CRC::CRCu16(crc) => crc.digest_bits(data, bit_len),
+            CRC::CRCu32(crc) => crc.digest_bits(data, bit_len),
+            CRC::CRCu64(crc) => crc.digest_bits(data, bit_len),
+            CRC::CRCu128(crc) => crc.digest_bits(data, bit_len),
+        }
+    }
+
+    /// Digest the first `bit_len` bits of some data, taking the bits of each byte from the least significant one (LSB-first).
+    #[inline]
+    pub fn digest_bits_lsb(&mut self, data: &[u8], bit_len: usize) {
+        match self {
+            CRC::CRCu8(crc) => crc.digest_bits_lsb(data, bit_len),
+            CRC::CRCu16(crc) => crc.digest_bits_lsb(data, bit_len),
+            CRC::CRCu32(crc) => crc.digest_bits_lsb(data, bit_len),
+            CRC::CRCu64(crc) => crc.digest_bits_lsb(data, bit_len),
+            CRC::CRCu128(crc) => crc.digest_bits_lsb(data, bit_len),
+        }
+    }
+
+    /// Digest the lowest `bits` bits of a symbol, from the most significant one (MSB-first).
+    #[inline]
+    pub fn digest_symbol(&mut self, symbol: u64, bits: u8) {
+        match self {
+            CRC::CRCu8(crc) => crc.digest_symbol(symbol, bits),
+            CRC::CRCu16(crc) => crc.digest_symbol(symbol, bits),
+            CRC::CRCu32(crc) => crc.digest_symbol(symbol, bits),
+            CRC::CRCu64(crc) => crc.digest_symbol(symbol, bits),
+            CRC::CRCu128(crc) => crc.digest_symbol(symbol, bits),
+        }
+    }
+
+    /// Digest the lowest `bits` bits of a symbol, from the least significant one (LSB-first).
+    #[inline]
+    pub fn digest_symbol_lsb(&mut self, symbol: u64, bits: u8) {
+        match self {
+            CRC::CRCu8(crc) => crc.digest_symbol_lsb(symbol, bits),
+            CRC::CRCu16(crc) => crc.digest_symbol_lsb(symbol, bits),
+            CRC::CRCu32(crc) => crc.digest_symbol_lsb(symbol, bits),
+            CRC::CRCu64(crc) => crc.digest_symbol_lsb(symbol, bits),
+            CRC::CRCu128(crc) => crc.digest_symbol_lsb(symbol, bits),
         }
     }
 
@@ -240,10 +550,13 @@ impl CRC {
             CRC::CRCu16(crc) => crc.reset(),
             CRC::CRCu32(crc) => crc.reset(),
             CRC::CRCu64(crc) => crc.reset(),
+            CRC::CRCu128(crc) => crc.reset(),
         }
     }
 
     /// Get the current CRC value (it always returns a `u64` value). You can continue calling `digest` method even after getting a CRC value.
+    ///
+    /// The value of a CRC wider than 64 bits is truncated to its lowest 64 bits, so use `get_crc_u128` for it.
     #[inline]
     pub fn get_crc(&mut self) -> u64 {
         match self {
@@ -251,6 +564,129 @@ impl CRC {
             CRC::CRCu16(crc) => crc.get_crc() as u64,
             CRC::CRCu32(crc) => crc.get_crc() as u64,
             CRC::CRCu64(crc) => crc.get_crc(),
+            CRC::CRCu128(crc) => crc.get_crc() as u64,
+        }
+    }
+
+    /// Get the current CRC value as a `u128` value, which is never truncated.
+    #[inline]
+    pub const fn get_crc_u128(&self) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => crc.get_crc() as u128,
+            CRC::CRCu16(crc) => crc.get_crc() as u128,
+            CRC::CRCu32(crc) => crc.get_crc() as u128,
+            CRC::CRCu64(crc) => crc.get_crc() as u128,
+            CRC::CRCu128(crc) => crc.get_crc(),
+        }
+    }
+
+    /// Get the length of bits.
+    #[inline]
+    pub const fn width(&self) -> u8 {
+        match self {
+            CRC::CRCu8(crc) => crc.width(),
+            CRC::CRCu16(crc) => crc.width(),
+            CRC::CRCu32(crc) => crc.width(),
+            CRC::CRCu64(crc) => crc.width(),
+            CRC::CRCu128(crc) => crc.width(),
+        }
+    }
+
+    /// Get the expression, which is not reflected even if the input is reflected.
+    #[inline]
+    pub const fn poly(&self) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => crc.poly() as u128,
+            CRC::CRCu16(crc) => crc.poly() as u128,
+            CRC::CRCu32(crc) => crc.poly() as u128,
+            CRC::CRCu64(crc) => crc.poly() as u128,
+            CRC::CRCu128(crc) => crc.poly(),
+        }
+    }
+
+    /// Get the initial value.
+    #[inline]
+    pub const fn init(&self) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => crc.init() as u128,
+            CRC::CRCu16(crc) => crc.init() as u128,
+            CRC::CRCu32(crc) => crc.init() as u128,
+            CRC::CRCu64(crc) => crc.init() as u128,
+            CRC::CRCu128(crc) => crc.init(),
+        }
+    }
+
+    /// Whether the input is reflected.
+    #[inline]
+    pub const fn refin(&self) -> bool {
+        match self {
+            CRC::CRCu8(crc) => crc.refin(),
+            CRC::CRCu16(crc) => crc.refin(),
+            CRC::CRCu32(crc) => crc.refin(),
+            CRC::CRCu64(crc) => crc.refin(),
+            CRC::CRCu128(crc) => crc.refin(),
+        }
+    }
+
+    /// Whether the output is reflected.
+    #[inline]
+    pub const fn refout(&self) -> bool {
+        match self {
+            CRC::CRCu8(crc) => crc.refout(),
+            CRC::CRCu16(crc) => crc.refout(),
+            CRC::CRCu32(crc) => crc.refout(),
+            CRC::CRCu64(crc) => crc.refout(),
+            CRC::CRCu128(crc) => crc.refout(),
+        }
+    }
+
+    /// Get the final xor value.
+    #[inline]
+    pub const fn xorout(&self) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => crc.xorout() as u128,
+            CRC::CRCu16(crc) => crc.xorout() as u128,
+            CRC::CRCu32(crc) => crc.xorout() as u128,
+            CRC::CRCu64(crc) => crc.xorout() as u128,
+            CRC::CRCu128(crc) => crc.xorout(),
+        }
+    }
+
+    /// Get the parameters of this instance, along with the check value and the residue. The name is empty.
+    #[inline]
+    pub const fn parameters(&self) -> CRCParameters {
+        match self {
+            CRC::CRCu8(crc) => crc.parameters(),
+            CRC::CRCu16(crc) => crc.parameters(),
+            CRC::CRCu32(crc) => crc.parameters(),
+            CRC::CRCu64(crc) => crc.parameters(),
+            CRC::CRCu128(crc) => crc.parameters(),
+        }
+    }
+
+    /// Combine the CRC values of two adjacent blocks of data into the CRC value of the concatenated data, without the data. `len_b` is the length of the second block in bytes. Only the parameters of this instance are used, not its current state.
+    ///
+    /// The values of a CRC wider than 64 bits are truncated to their lowest 64 bits, so use `combine_u128` for it.
+    #[inline]
+    pub fn combine(&self, crc_a: u64, crc_b: u64, len_b: u64) -> u64 {
+        match self {
+            CRC::CRCu8(crc) => crc.combine(crc_a as u8, crc_b as u8, len_b) as u64,
+            CRC::CRCu16(crc) => crc.combine(crc_a as u16, crc_b as u16, len_b) as u64,
+            CRC::CRCu32(crc) => crc.combine(crc_a as u32, crc_b as u32, len_b) as u64,
+            CRC::CRCu64(crc) => crc.combine(crc_a, crc_b, len_b),
+            CRC::CRCu128(crc) => crc.combine(crc_a as u128, crc_b as u128, len_b) as u64,
+        }
+    }
+
+    /// Combine the CRC values of two adjacent blocks of data like `combine`, but with `u128` values, which are never truncated.
+    #[inline]
+    pub fn combine_u128(&self, crc_a: u128, crc_b: u128, len_b: u64) -> u128 {
+        match self {
+            CRC::CRCu8(crc) => crc.combine(crc_a as u8, crc_b as u8, len_b) as u128,
+            CRC::CRCu16(crc) => crc.combine(crc_a as u16, crc_b as u16, len_b) as u128,
+            CRC::CRCu32(crc) => crc.combine(crc_a as u32, crc_b as u32, len_b) as u128,
+            CRC::CRCu64(crc) => crc.combine(crc_a as u64, crc_b as u64, len_b) as u128,
+            CRC::CRCu128(crc) => crc.combine(crc_a, crc_b, len_b),
         }
     }
 
@@ -269,6 +705,7 @@ impl CRC {
             CRC::CRCu16(crc) => crc.get_crc_vec_le(),
             CRC::CRCu32(crc) => crc.get_crc_vec_le(),
             CRC::CRCu64(crc) => crc.get_crc_vec_le(),
+            CRC::CRCu128(crc) => crc.get_crc_vec_le(),
         }
     }
 
@@ -287,6 +724,56 @@ impl CRC {
             CRC::CRCu16(crc) => crc.get_crc_vec_be(),
             CRC::CRCu32(crc) => crc.get_crc_vec_be(),
             CRC::CRCu64(crc) => crc.get_crc_vec_be(),
+            CRC::CRCu128(crc) => crc.get_crc_vec_be(),
+        }
+    }
+
+    /// Write the current CRC value into the beginning of `buffer` in the given byte order, and return the number of written bytes, which corresponds to the CRC bits. It panics if `buffer` is too short.
+    #[inline]
+    pub fn write_crc(&self, buffer: &mut [u8], endian: Endian) -> usize {
+        match self {
+            CRC::CRCu8(crc) => crc.write_crc(buffer, endian),
+            CRC::CRCu16(crc) => crc.write_crc(buffer, endian),
+            CRC::CRCu32(crc) => crc.write_crc(buffer, endian),
+            CRC::CRCu64(crc) => crc.write_crc(buffer, endian),
+            CRC::CRCu128(crc) => crc.write_crc(buffer, endian),
+        }
+    }
+
+    /// Append the current CRC value to `buffer` in the given byte order.
+    #[cfg(feature = "default")]
+    #[inline]
+    pub fn append_crc(&self, buffer: &mut Vec<u8>, endian: Endian) {
+        match self {
+            CRC::CRCu8(crc) => crc.append_crc(buffer, endian),
+            CRC::CRCu16(crc) => crc.append_crc(buffer, endian),
+            CRC::CRCu32(crc) => crc.append_crc(buffer, endian),
+            CRC::CRCu64(crc) => crc.append_crc(buffer, endian),
+            CRC::CRCu128(crc) => crc.append_crc(buffer, endian),
+        }
+    }
+
+    /// Check whether `frame` is some data followed by its CRC value in the given byte order. The data is digested by a clone of this instance, so this instance is not changed.
+    #[inline]
+    pub fn verify_frame(&self, frame: &[u8], endian: Endian) -> bool {
+        match self {
+            CRC::CRCu8(crc) => crc.verify_frame(frame, endian),
+            CRC::CRCu16(crc) => crc.verify_frame(frame, endian),
+            CRC::CRCu32(crc) => crc.verify_frame(frame, endian),
+            CRC::CRCu64(crc) => crc.verify_frame(frame, endian),
+            CRC::CRCu128(crc) => crc.verify_frame(frame, endian),
+        }
+    }
+
+    /// Check whether the data digested so far is followed by its own CRC value, by comparing the current CRC value with the residue (xored with the final xor value) of the algorithm, so a frame can be verified in one pass. The CRC value must be in the byte order which matches the reflection (`Endian::Little` if reflected, `Endian::Big` otherwise), and the length of bits must be a multiple of 8.
+    #[inline]
+    pub const fn check_residue(&self) -> bool {
+        match self {
+            CRC::CRCu8(crc) => crc.check_residue(),
+            CRC::CRCu16(crc) => crc.check_residue(),
+            CRC::CRCu32(crc) => crc.check_residue(),
+            CRC::CRCu64(crc) => crc.check_residue(),
+            CRC::CRCu128(crc) => crc.check_residue(),
         }
     }
 }
@@ -294,445 +781,639 @@ impl CRC {
 impl CRC {
     // TODO: CRC-3
 
-    pub fn crc3gsm() -> CRC {
+    pub const fn crc3gsm() -> CRC {
         CRC::CRCu8(CRCu8::crc3gsm())
     }
 
+    #[inline]
+    pub const fn crc3rohc() -> CRC {
+        CRC::CRCu8(CRCu8::crc3rohc())
+    }
+
     // TODO: CRC-4
 
-    pub fn crc4itu() -> CRC {
+    pub const fn crc4itu() -> CRC {
         CRC::CRCu8(CRCu8::crc4itu())
     }
 
-    pub fn crc4interlaken() -> CRC {
+    pub const fn crc4interlaken() -> CRC {
         CRC::CRCu8(CRCu8::crc4interlaken())
     }
 
     // TODO: CRC-5
 
-    pub fn crc5epc() -> CRC {
+    pub const fn crc5epc() -> CRC {
         CRC::CRCu8(CRCu8::crc5epc())
     }
 
-    pub fn crc5itu() -> CRC {
+    pub const fn crc5itu() -> CRC {
         CRC::CRCu8(CRCu8::crc5itu())
     }
 
-    pub fn crc5usb() -> CRC {
+    pub const fn crc5usb() -> CRC {
         CRC::CRCu8(CRCu8::crc5usb())
     }
 
+    #[inline]
+    pub const fn crc5epc_c1g2() -> CRC {
+        CRC::CRCu8(CRCu8::crc5epc_c1g2())
+    }
+
     // TODO: CRC-6
 
     #[inline]
-    pub fn crc6cdma2000_a() -> CRC {
+    pub const fn crc6cdma2000_a() -> CRC {
         CRC::CRCu8(CRCu8::crc6cdma2000_a())
     }
 
     #[inline]
-    pub fn crc6cdma2000_b() -> CRC {
+    pub const fn crc6cdma2000_b() -> CRC {
         CRC::CRCu8(CRCu8::crc6cdma2000_b())
     }
 
     #[inline]
-    pub fn crc6darc() -> CRC {
+    pub const fn crc6darc() -> CRC {
         CRC::CRCu8(CRCu8::crc6darc())
     }
 
     #[inline]
-    pub fn crc6gsm() -> CRC {
+    pub const fn crc6gsm() -> CRC {
         CRC::CRCu8(CRCu8::crc6gsm())
     }
 
     #[inline]
-    pub fn crc6itu() -> CRC {
+    pub const fn crc6itu() -> CRC {
         CRC::CRCu8(CRCu8::crc6itu())
     }
 
     // TODO: CRC-7
 
     #[inline]
-    pub fn crc7() -> CRC {
+    pub const fn crc7() -> CRC {
         CRC::CRCu8(CRCu8::crc7())
     }
 
     #[inline]
-    pub fn crc7umts() -> CRC {
+    pub const fn crc7umts() -> CRC {
         CRC::CRCu8(CRCu8::crc7umts())
     }
 
+    #[inline]
+    pub const fn crc7rohc() -> CRC {
+        CRC::CRCu8(CRCu8::crc7rohc())
+    }
+
     // TODO: CRC-8
 
     #[inline]
-    pub fn crc8() -> CRC {
+    pub const fn crc8() -> CRC {
         CRC::CRCu8(CRCu8::crc8())
     }
 
     #[inline]
-    pub fn crc8cdma2000() -> CRC {
+    pub const fn crc8cdma2000() -> CRC {
         CRC::CRCu8(CRCu8::crc8cdma2000())
     }
 
     #[inline]
-    pub fn crc8darc() -> CRC {
+    pub const fn crc8darc() -> CRC {
         CRC::CRCu8(CRCu8::crc8darc())
     }
 
     #[inline]
-    pub fn crc8dvb_s2() -> CRC {
+    pub const fn crc8dvb_s2() -> CRC {
         CRC::CRCu8(CRCu8::crc8dvb_s2())
     }
 
     #[inline]
-    pub fn crc8ebu() -> CRC {
+    pub const fn crc8ebu() -> CRC {
         CRC::CRCu8(CRCu8::crc8ebu())
     }
 
     #[inline]
-    pub fn crc8icode() -> CRC {
+    pub const fn crc8icode() -> CRC {
         CRC::CRCu8(CRCu8::crc8icode())
     }
 
     #[inline]
-    pub fn crc8itu() -> CRC {
+    pub const fn crc8itu() -> CRC {
         CRC::CRCu8(CRCu8::crc8itu())
     }
 
     #[inline]
-    pub fn crc8maxim() -> CRC {
+    pub const fn crc8maxim() -> CRC {
         CRC::CRCu8(CRCu8::crc8maxim())
     }
 
     #[inline]
-    pub fn crc8rohc() -> CRC {
+    pub const fn crc8rohc() -> CRC {
         CRC::CRCu8(CRCu8::crc8rohc())
     }
 
     #[inline]
-    pub fn crc8wcdma() -> CRC {
+    pub const fn crc8wcdma() -> CRC {
         CRC::CRCu8(CRCu8::crc8wcdma())
     }
 
+    #[inline]
+    pub const fn crc8autosar() -> CRC {
+        CRC::CRCu8(CRCu8::crc8autosar())
+    }
+
+    #[inline]
+    pub const fn crc8bluetooth() -> CRC {
+        CRC::CRCu8(CRCu8::crc8bluetooth())
+    }
+
+    #[inline]
+    pub const fn crc8gsm_a() -> CRC {
+        CRC::CRCu8(CRCu8::crc8gsm_a())
+    }
+
+    #[inline]
+    pub const fn crc8gsm_b() -> CRC {
+        CRC::CRCu8(CRCu8::crc8gsm_b())
+    }
+
+    #[inline]
+    pub const fn crc8hitag() -> CRC {
+        CRC::CRCu8(CRCu8::crc8hitag())
+    }
+
+    #[inline]
+    pub const fn crc8lte() -> CRC {
+        CRC::CRCu8(CRCu8::crc8lte())
+    }
+
+    #[inline]
+    pub const fn crc8mifare_mad() -> CRC {
+        CRC::CRCu8(CRCu8::crc8mifare_mad())
+    }
+
+    #[inline]
+    pub const fn crc8nrsc5() -> CRC {
+        CRC::CRCu8(CRCu8::crc8nrsc5())
+    }
+
+    #[inline]
+    pub const fn crc8opensafety() -> CRC {
+        CRC::CRCu8(CRCu8::crc8opensafety())
+    }
+
+    #[inline]
+    pub const fn crc8sae_j1850() -> CRC {
+        CRC::CRCu8(CRCu8::crc8sae_j1850())
+    }
+
     // TODO: CRC-10
 
     #[inline]
-    pub fn crc10() -> CRC {
+    pub const fn crc10() -> CRC {
         CRC::CRCu16(CRCu16::crc10())
     }
 
     #[inline]
-    pub fn crc10cdma2000() -> CRC {
+    pub const fn crc10cdma2000() -> CRC {
         CRC::CRCu16(CRCu16::crc10cdma2000())
     }
 
     #[inline]
-    pub fn crc10gsm() -> CRC {
+    pub const fn crc10gsm() -> CRC {
         CRC::CRCu16(CRCu16::crc10gsm())
     }
 
     // TODO: CRC-11
 
     #[inline]
-    pub fn crc11() -> CRC {
+    pub const fn crc11() -> CRC {
         CRC::CRCu16(CRCu16::crc11())
     }
 
+    #[inline]
+    pub const fn crc11umts() -> CRC {
+        CRC::CRCu16(CRCu16::crc11umts())
+    }
+
     // TODO: CRC-12
 
     #[inline]
-    pub fn crc12() -> CRC {
+    pub const fn crc12() -> CRC {
         CRC::CRCu16(CRCu16::crc12())
     }
 
     #[inline]
-    pub fn crc12cdma2000() -> CRC {
+    pub const fn crc12umts() -> CRC {
+        CRC::CRCu16(CRCu16::crc12umts())
+    }
+
+    #[inline]
+    pub const fn crc12cdma2000() -> CRC {
         CRC::CRCu16(CRCu16::crc12cdma2000())
     }
 
     #[inline]
-    pub fn crc12gsm() -> CRC {
+    pub const fn crc12gsm() -> CRC {
         CRC::CRCu16(CRCu16::crc12gsm())
     }
 
     // TODO: CRC-13
 
     #[inline]
-    pub fn crc13bbc() -> CRC {
+    pub const fn crc13bbc() -> CRC {
         CRC::CRCu16(CRCu16::crc13bbc())
     }
 
     // TODO: CRC-14
 
     #[inline]
-    pub fn crc14darc() -> CRC {
+    pub const fn crc14darc() -> CRC {
         CRC::CRCu16(CRCu16::crc14darc())
     }
 
     #[inline]
-    pub fn crc14gsm() -> CRC {
+    pub const fn crc14gsm() -> CRC {
         CRC::CRCu16(CRCu16::crc14gsm())
     }
 
     // TODO: CRC-15
 
     #[inline]
-    pub fn crc15can() -> CRC {
+    pub const fn crc15can() -> CRC {
         CRC::CRCu16(CRCu16::crc15can())
     }
 
     #[inline]
-    pub fn crc15mpt1327() -> CRC {
+    pub const fn crc15mpt1327() -> CRC {
         CRC::CRCu16(CRCu16::crc15mpt1327())
     }
 
     // TODO: CRC-16
 
     #[inline]
-    pub fn crc16() -> CRC {
+    pub const fn crc16() -> CRC {
         CRC::CRCu16(CRCu16::crc16())
     }
 
     #[inline]
-    pub fn crc16ccitt_false() -> CRC {
+    pub const fn crc16ccitt_false() -> CRC {
         CRC::CRCu16(CRCu16::crc16ccitt_false())
     }
 
     #[inline]
-    pub fn crc16aug_ccitt() -> CRC {
+    pub const fn crc16aug_ccitt() -> CRC {
         CRC::CRCu16(CRCu16::crc16aug_ccitt())
     }
 
     #[inline]
-    pub fn crc16buypass() -> CRC {
+    pub const fn crc16buypass() -> CRC {
         CRC::CRCu16(CRCu16::crc16buypass())
     }
 
     #[inline]
-    pub fn crc16cdma2000() -> CRC {
+    pub const fn crc16cdma2000() -> CRC {
         CRC::CRCu16(CRCu16::crc16cdma2000())
     }
 
     #[inline]
-    pub fn crc16dds_110() -> CRC {
+    pub const fn crc16dds_110() -> CRC {
         CRC::CRCu16(CRCu16::crc16dds_110())
     }
 
     #[inline]
-    pub fn crc16dect_r() -> CRC {
+    pub const fn crc16dect_r() -> CRC {
         CRC::CRCu16(CRCu16::crc16dect_r())
     }
 
     #[inline]
-    pub fn crc16dect_x() -> CRC {
+    pub const fn crc16dect_x() -> CRC {
         CRC::CRCu16(CRCu16::crc16dect_x())
     }
 
     #[inline]
-    pub fn crc16dnp() -> CRC {
+    pub const fn crc16dnp() -> CRC {
         CRC::CRCu16(CRCu16::crc16dnp())
     }
 
     #[inline]
-    pub fn crc16en_13757() -> CRC {
+    pub const fn crc16en_13757() -> CRC {
         CRC::CRCu16(CRCu16::crc16en_13757())
     }
 
     #[inline]
-    pub fn crc16genibus() -> CRC {
+    pub const fn crc16genibus() -> CRC {
         CRC::CRCu16(CRCu16::crc16genibus())
     }
 
     #[inline]
-    pub fn crc16maxim() -> CRC {
+    pub const fn crc16maxim() -> CRC {
         CRC::CRCu16(CRCu16::crc16maxim())
     }
 
     #[inline]
-    pub fn crc16mcrf4cc() -> CRC {
+    pub const fn crc16mcrf4cc() -> CRC {
         CRC::CRCu16(CRCu16::crc16mcrf4cc())
     }
 
     #[inline]
-    pub fn crc16riello() -> CRC {
+    pub const fn crc16riello() -> CRC {
         CRC::CRCu16(CRCu16::crc16riello())
     }
 
     #[inline]
-    pub fn crc16t10_dif() -> CRC {
+    pub const fn crc16t10_dif() -> CRC {
         CRC::CRCu16(CRCu16::crc16t10_dif())
     }
 
     #[inline]
-    pub fn crc16teledisk() -> CRC {
+    pub const fn crc16teledisk() -> CRC {
         CRC::CRCu16(CRCu16::crc16teledisk())
     }
 
     #[inline]
-    pub fn crc16tms13157() -> CRC {
+    pub const fn crc16tms13157() -> CRC {
         CRC::CRCu16(CRCu16::crc16tms13157())
     }
 
     #[inline]
-    pub fn crc16usb() -> CRC {
+    pub const fn crc16usb() -> CRC {
         CRC::CRCu16(CRCu16::crc16usb())
     }
 
     #[inline]
-    pub fn crc_a() -> CRC {
+    pub const fn crc_a() -> CRC {
         CRC::CRCu16(CRCu16::crc_a())
     }
 
     #[inline]
-    pub fn crc16kermit() -> CRC {
+    pub const fn crc16kermit() -> CRC {
         CRC::CRCu16(CRCu16::crc16kermit())
     }
 
     #[inline]
-    pub fn crc16modbus() -> CRC {
+    pub const fn crc16modbus() -> CRC {
         CRC::CRCu16(CRCu16::crc16modbus())
     }
 
     #[inline]
-    pub fn crc16_x25() -> CRC {
+    pub const fn crc16_x25() -> CRC {
         CRC::CRCu16(CRCu16::crc16_x25())
     }
 
     #[inline]
-    pub fn crc16xmodem() -> CRC {
+    pub const fn crc16ibm_sdlc() -> CRC {
+        CRC::CRCu16(CRCu16::crc16ibm_sdlc())
+    }
+
+    #[inline]
+    pub const fn crc16xmodem() -> CRC {
         CRC::CRCu16(CRCu16::crc16xmodem())
     }
 
+    #[inline]
+    pub const fn crc16cms() -> CRC {
+        CRC::CRCu16(CRCu16::crc16cms())
+    }
+
+    #[inline]
+    pub const fn crc16gsm() -> CRC {
+        CRC::CRCu16(CRCu16::crc16gsm())
+    }
+
+    #[inline]
+    pub const fn crc16lj1200() -> CRC {
+        CRC::CRCu16(CRCu16::crc16lj1200())
+    }
+
+    #[inline]
+    pub const fn crc16m17() -> CRC {
+        CRC::CRCu16(CRCu16::crc16m17())
+    }
+
+    #[inline]
+    pub const fn crc16nrsc5() -> CRC {
+        CRC::CRCu16(CRCu16::crc16nrsc5())
+    }
+
+    #[inline]
+    pub const fn crc16opensafety_a() -> CRC {
+        CRC::CRCu16(CRCu16::crc16opensafety_a())
+    }
+
+    #[inline]
+    pub const fn crc16opensafety_b() -> CRC {
+        CRC::CRCu16(CRCu16::crc16opensafety_b())
+    }
+
+    #[inline]
+    pub const fn crc16profibus() -> CRC {
+        CRC::CRCu16(CRCu16::crc16profibus())
+    }
+
     // TODO: CRC-17
 
     #[inline]
-    pub fn crc17can() -> CRC {
+    pub const fn crc17can() -> CRC {
         CRC::CRCu32(CRCu32::crc17can())
     }
 
     // TODO: CRC-21
 
     #[inline]
-    pub fn crc21can() -> CRC {
+    pub const fn crc21can() -> CRC {
         CRC::CRCu32(CRCu32::crc21can())
     }
 
     // TODO: CRC-24
 
     #[inline]
-    pub fn crc24() -> CRC {
+    pub const fn crc24() -> CRC {
         CRC::CRCu32(CRCu32::crc24())
     }
 
     #[inline]
-    pub fn crc24ble() -> CRC {
+    pub const fn crc24ble() -> CRC {
         CRC::CRCu32(CRCu32::crc24ble())
     }
 
     #[inline]
-    pub fn crc24flexray_a() -> CRC {
+    pub const fn crc24flexray_a() -> CRC {
         CRC::CRCu32(CRCu32::crc24flexray_a())
     }
 
     #[inline]
-    pub fn crc24flexray_b() -> CRC {
+    pub const fn crc24flexray_b() -> CRC {
         CRC::CRCu32(CRCu32::crc24flexray_b())
     }
 
     #[inline]
-    pub fn crc24lte_a() -> CRC {
+    pub const fn crc24lte_a() -> CRC {
         CRC::CRCu32(CRCu32::crc24lte_a())
     }
 
     #[inline]
-    pub fn crc24lte_b() -> CRC {
+    pub const fn crc24lte_b() -> CRC {
         CRC::CRCu32(CRCu32::crc24lte_b())
     }
 
     #[inline]
-    pub fn crc24os9() -> CRC {
+    pub const fn crc24os9() -> CRC {
         CRC::CRCu32(CRCu32::crc24os9())
     }
 
+    #[inline]
+    pub const fn crc24interlaken() -> CRC {
+        CRC::CRCu32(CRCu32::crc24interlaken())
+    }
+
     // TODO: CRC-30
 
     #[inline]
-    pub fn crc30cdma() -> CRC {
+    pub const fn crc30cdma() -> CRC {
         CRC::CRCu32(CRCu32::crc30cdma())
     }
 
+    // TODO: CRC-31
+
+    #[inline]
+    pub const fn crc31philips() -> CRC {
+        CRC::CRCu32(CRCu32::crc31philips())
+    }
+
     // TODO: CRC-32
 
     #[inline]
-    pub fn crc32() -> CRC {
+    pub const fn crc32() -> CRC {
         CRC::CRCu32(CRCu32::crc32())
     }
 
     #[inline]
-    pub fn crc32mhash() -> CRC {
+    pub const fn crc32mhash() -> CRC {
         CRC::CRCu32(CRCu32::crc32mhash())
     }
 
     #[inline]
-    pub fn crc32bzip2() -> CRC {
+    pub const fn crc32bzip2() -> CRC {
         CRC::CRCu32(CRCu32::crc32bzip2())
     }
 
     #[inline]
-    pub fn crc32c() -> CRC {
+    pub const fn crc32c() -> CRC {
         CRC::CRCu32(CRCu32::crc32c())
     }
 
     #[inline]
-    pub fn crc32d() -> CRC {
+    pub const fn crc32iscsi() -> CRC {
+        CRC::CRCu32(CRCu32::crc32iscsi())
+    }
+
+    #[inline]
+    pub const fn crc32d() -> CRC {
         CRC::CRCu32(CRCu32::crc32d())
     }
 
     #[inline]
-    pub fn crc32mpeg2() -> CRC {
+    pub const fn crc32mpeg2() -> CRC {
         CRC::CRCu32(CRCu32::crc32mpeg2())
     }
 
     #[inline]
-    pub fn crc32posix() -> CRC {
+    pub const fn crc32posix() -> CRC {
         CRC::CRCu32(CRCu32::crc32posix())
     }
 
     #[inline]
-    pub fn crc32q() -> CRC {
+    pub const fn crc32q() -> CRC {
         CRC::CRCu32(CRCu32::crc32q())
     }
 
     #[inline]
-    pub fn crc32jamcrc() -> CRC {
+    pub const fn crc32jamcrc() -> CRC {
         CRC::CRCu32(CRCu32::crc32jamcrc())
     }
 
     #[inline]
-    pub fn crc32xfer() -> CRC {
+    pub const fn crc32xfer() -> CRC {
         CRC::CRCu32(CRCu32::crc32xfer())
     }
 
+    #[inline]
+    pub const fn crc32autosar() -> CRC {
+        CRC::CRCu32(CRCu32::crc32autosar())
+    }
+
+    #[inline]
+    pub const fn crc32cd_rom_edc() -> CRC {
+        CRC::CRCu32(CRCu32::crc32cd_rom_edc())
+    }
+
+    #[inline]
+    pub const fn crc32mef() -> CRC {
+        CRC::CRCu32(CRCu32::crc32mef())
+    }
+
     // TODO: CRC-40
 
     #[inline]
-    pub fn crc40gsm() -> CRC {
+    pub const fn crc40gsm() -> CRC {
         CRC::CRCu64(CRCu64::crc40gsm())
     }
 
     // TODO: CRC-64
 
     #[inline]
-    pub fn crc64() -> CRC {
+    pub const fn crc64() -> CRC {
         CRC::CRCu64(CRCu64::crc64())
     }
 
     #[inline]
-    pub fn crc64iso() -> CRC {
+    pub const fn crc64we() -> CRC {
+        CRC::CRCu64(CRCu64::crc64we())
+    }
+
+    #[inline]
+    pub const fn crc64iso() -> CRC {
         CRC::CRCu64(CRCu64::crc64iso())
     }
 
     #[inline]
-    pub fn crc64jones() -> CRC {
+    pub const fn crc64go_iso() -> CRC {
+        CRC::CRCu64(CRCu64::crc64go_iso())
+    }
+
+    #[inline]
+    pub const fn crc64jones() -> CRC {
         CRC::CRCu64(CRCu64::crc64jones())
     }
+
+    #[inline]
+    pub const fn crc64redis() -> CRC {
+        CRC::CRCu64(CRCu64::crc64redis())
+    }
+
+    #[inline]
+    pub const fn crc64ecma182() -> CRC {
+        CRC::CRCu64(CRCu64::crc64ecma182())
+    }
+
+    #[inline]
+    pub const fn crc64ms() -> CRC {
+        CRC::CRCu64(CRCu64::crc64ms())
+    }
+
+    #[inline]
+    pub const fn crc64nvme() -> CRC {
+        CRC::CRCu64(CRCu64::crc64nvme())
+    }
+
+    #[inline]
+    pub const fn crc64xz() -> CRC {
+        CRC::CRCu64(CRCu64::crc64xz())
+    }
+
+    // TODO: CRC-82
+
+    #[inline]
+    pub const fn crc82darc() -> CRC {
+        CRC::CRCu128(CRCu128::crc82darc())
+    }
 }
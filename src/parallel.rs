@@ -0,0 +1,26 @@
+//! Digest large data on multiple threads by splitting it into chunks and combining the CRC values of the chunks.
+
+use rayon::prelude::*;
+
+/// The minimum length of data which is worth being digested in parallel.
+pub(crate) const MIN_LENGTH: usize = 1024 * 1024;
+
+/// The minimum length of a chunk digested by a thread.
+const MIN_CHUNK_LENGTH: usize = 256 * 1024;
+
+/// Compute the CRC value of `data` (which must not be empty) by digesting its chunks with `digest_chunk` in parallel and combining the results in order with `combine`.
+pub(crate) fn digest<T, D, C>(data: &[u8], digest_chunk: D, combine: C) -> T
+where
+    T: Send,
+    D: Fn(&[u8]) -> T + Sync,
+    C: Fn(T, T, u64) -> T + Sync, {
+    debug_assert!(!data.is_empty());
+
+    let chunk_length = data.len().div_ceil(rayon::current_num_threads()).max(MIN_CHUNK_LENGTH);
+
+    data.par_chunks(chunk_length)
+        .map(|chunk| (digest_chunk(chunk), chunk.len() as u64))
+        .reduce_with(|(crc_a, len_a), (crc_b, len_b)| (combine(crc_a, crc_b, len_b), len_a + len_b))
+        .map(|(crc, _)| crc)
+        .unwrap()
+}
@@ -0,0 +1,1718 @@
+//! The parameters of CRC algorithms in the Rocksoft model, which is the one used by the CRC RevEng catalogue.
+
+use catalogue;
+use error::{CRCError, check_parameters};
+
+/// The input whose CRC value is the check value of an algorithm.
+const CHECK_INPUT: &[u8] = b"123456789";
+
+/// The parameters of a CRC algorithm.
+///
+/// `poly`, `init` and `xorout` are never reflected, and the polynomial does not contain its highest term. `check` is the CRC value of the ASCII string `"123456789"`. `residue` is the value of the register (before `xorout` is applied) after digesting some data followed by its own CRC value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CRCParameters {
+    pub width: u8,
+    pub poly: u128,
+    pub init: u128,
+    pub refin: bool,
+    pub refout: bool,
+    pub xorout: u128,
+    pub check: u128,
+    pub residue: u128,
+    pub name: &'static str,
+}
+
+/// A builder of `CRCParameters`, which computes the check value and the residue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CRCParametersBuilder {
+    width: u8,
+    poly: u128,
+    init: u128,
+    refin: bool,
+    refout: bool,
+    xorout: u128,
+    name: &'static str,
+}
+
+impl CRCParameters {
+    /// Find the parameters of an algorithm in the CRC RevEng catalogue by its name (like `"CRC-32/ISO-HDLC"`) or one of its aliases (like `"CRC-32"` or `"PKZIP"`). The name is case-insensitive.
+    pub fn from_name(name: &str) -> Option<&'static CRCParameters> {
+        catalogue::find(name).map(|(parameters, _)| *parameters)
+    }
+
+    /// Iterate over the parameters of all algorithms in the CRC RevEng catalogue, which are all built in, in order of their widths.
+    pub fn catalogue() -> impl Iterator<Item = &'static CRCParameters> {
+        catalogue::CATALOGUE.iter().map(|(parameters, _)| *parameters)
+    }
+
+    /// Iterate over the aliases of this algorithm in the CRC RevEng catalogue.
+    pub fn aliases(&self) -> impl Iterator<Item = &'static str> {
+        let name = self.name;
+
+        catalogue::ALIASES.iter().filter(move |(_, canonical)| *canonical == name).map(|(alias, _)| *alias)
+    }
+
+    /// Create a builder with the parameters of this algorithm except for its name, in order to derive a variant of it.
+    #[inline]
+    pub const fn to_builder(&self) -> CRCParametersBuilder {
+        CRCParametersBuilder {
+            width: self.width,
+            poly: self.poly,
+            init: self.init,
+            refin: self.refin,
+            refout: self.refout,
+            xorout: self.xorout,
+            name: "",
+        }
+    }
+}
+
+impl CRCParameters {
+    /// The parameters of `crc3gsm`.
+    pub const CRC_3_GSM: CRCParameters = CRCParameters {
+        width: 3,
+        poly: 0x03,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x07,
+        check: 0x04,
+        residue: 0x02,
+        name: "CRC-3/GSM",
+    };
+
+    /// The parameters of `crc3rohc`.
+    pub const CRC_3_ROHC: CRCParameters = CRCParameters {
+        width: 3,
+        poly: 0x03,
+        init: 0x07,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x06,
+        residue: 0x00,
+        name: "CRC-3/ROHC",
+    };
+
+    /// The parameters of `crc4itu`.
+    pub const CRC_4_G_704: CRCParameters = CRCParameters {
+        width: 4,
+        poly: 0x03,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x07,
+        residue: 0x00,
+        name: "CRC-4/G-704",
+    };
+
+    /// The parameters of `crc4interlaken`.
+    pub const CRC_4_INTERLAKEN: CRCParameters = CRCParameters {
+        width: 4,
+        poly: 0x03,
+        init: 0x0F,
+        refin: false,
+        refout: false,
+        xorout: 0x0F,
+        check: 0x0B,
+        residue: 0x02,
+        name: "CRC-4/INTERLAKEN",
+    };
+
+    /// The parameters of `crc5epc`, which is not in the CRC RevEng catalogue, so it is named after the function.
+    pub const CRC_5_EPC: CRCParameters = CRCParameters {
+        width: 5,
+        poly: 0x09,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x06,
+        residue: 0x00,
+        name: "crc5epc",
+    };
+
+    /// The parameters of `crc5itu`.
+    pub const CRC_5_G_704: CRCParameters = CRCParameters {
+        width: 5,
+        poly: 0x15,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x07,
+        residue: 0x00,
+        name: "CRC-5/G-704",
+    };
+
+    /// The parameters of `crc5usb`.
+    pub const CRC_5_USB: CRCParameters = CRCParameters {
+        width: 5,
+        poly: 0x05,
+        init: 0x1F,
+        refin: true,
+        refout: true,
+        xorout: 0x1F,
+        check: 0x19,
+        residue: 0x06,
+        name: "CRC-5/USB",
+    };
+
+    /// The parameters of `crc5epc_c1g2`.
+    pub const CRC_5_EPC_C1G2: CRCParameters = CRCParameters {
+        width: 5,
+        poly: 0x09,
+        init: 0x09,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x00,
+        residue: 0x00,
+        name: "CRC-5/EPC-C1G2",
+    };
+
+    /// The parameters of `crc6cdma2000_a`.
+    pub const CRC_6_CDMA2000_A: CRCParameters = CRCParameters {
+        width: 6,
+        poly: 0x27,
+        init: 0x3F,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x0D,
+        residue: 0x00,
+        name: "CRC-6/CDMA2000-A",
+    };
+
+    /// The parameters of `crc6cdma2000_b`.
+    pub const CRC_6_CDMA2000_B: CRCParameters = CRCParameters {
+        width: 6,
+        poly: 0x07,
+        init: 0x3F,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x3B,
+        residue: 0x00,
+        name: "CRC-6/CDMA2000-B",
+    };
+
+    /// The parameters of `crc6darc`.
+    pub const CRC_6_DARC: CRCParameters = CRCParameters {
+        width: 6,
+        poly: 0x19,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x26,
+        residue: 0x00,
+        name: "CRC-6/DARC",
+    };
+
+    /// The parameters of `crc6gsm`.
+    pub const CRC_6_GSM: CRCParameters = CRCParameters {
+        width: 6,
+        poly: 0x2F,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x3F,
+        check: 0x13,
+        residue: 0x3A,
+        name: "CRC-6/GSM",
+    };
+
+    /// The parameters of `crc6itu`.
+    pub const CRC_6_G_704: CRCParameters = CRCParameters {
+        width: 6,
+        poly: 0x03,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x06,
+        residue: 0x00,
+        name: "CRC-6/G-704",
+    };
+
+    /// The parameters of `crc7`.
+    pub const CRC_7_MMC: CRCParameters = CRCParameters {
+        width: 7,
+        poly: 0x09,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x75,
+        residue: 0x00,
+        name: "CRC-7/MMC",
+    };
+
+    /// The parameters of `crc7umts`.
+    pub const CRC_7_UMTS: CRCParameters = CRCParameters {
+        width: 7,
+        poly: 0x45,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x61,
+        residue: 0x00,
+        name: "CRC-7/UMTS",
+    };
+
+    /// The parameters of `crc7rohc`.
+    pub const CRC_7_ROHC: CRCParameters = CRCParameters {
+        width: 7,
+        poly: 0x4F,
+        init: 0x7F,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x53,
+        residue: 0x00,
+        name: "CRC-7/ROHC",
+    };
+
+    /// The parameters of `crc8`.
+    pub const CRC_8_SMBUS: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x07,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0xF4,
+        residue: 0x00,
+        name: "CRC-8/SMBUS",
+    };
+
+    /// The parameters of `crc8cdma2000`.
+    pub const CRC_8_CDMA2000: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x9B,
+        init: 0xFF,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0xDA,
+        residue: 0x00,
+        name: "CRC-8/CDMA2000",
+    };
+
+    /// The parameters of `crc8darc`.
+    pub const CRC_8_DARC: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x39,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x15,
+        residue: 0x00,
+        name: "CRC-8/DARC",
+    };
+
+    /// The parameters of `crc8dvb_s2`.
+    pub const CRC_8_DVB_S2: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0xD5,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0xBC,
+        residue: 0x00,
+        name: "CRC-8/DVB-S2",
+    };
+
+    /// The parameters of `crc8ebu`.
+    pub const CRC_8_TECH_3250: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x1D,
+        init: 0xFF,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x97,
+        residue: 0x00,
+        name: "CRC-8/TECH-3250",
+    };
+
+    /// The parameters of `crc8icode`.
+    pub const CRC_8_I_CODE: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x1D,
+        init: 0xFD,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x7E,
+        residue: 0x00,
+        name: "CRC-8/I-CODE",
+    };
+
+    /// The parameters of `crc8itu`.
+    pub const CRC_8_I_432_1: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x07,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x55,
+        check: 0xA1,
+        residue: 0xAC,
+        name: "CRC-8/I-432-1",
+    };
+
+    /// The parameters of `crc8maxim`.
+    pub const CRC_8_MAXIM_DOW: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x31,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0xA1,
+        residue: 0x00,
+        name: "CRC-8/MAXIM-DOW",
+    };
+
+    /// The parameters of `crc8rohc`.
+    pub const CRC_8_ROHC: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x07,
+        init: 0xFF,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0xD0,
+        residue: 0x00,
+        name: "CRC-8/ROHC",
+    };
+
+    /// The parameters of `crc8wcdma`.
+    pub const CRC_8_WCDMA: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x9B,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x25,
+        residue: 0x00,
+        name: "CRC-8/WCDMA",
+    };
+
+    /// The parameters of `crc8autosar`.
+    pub const CRC_8_AUTOSAR: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x2F,
+        init: 0xFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFF,
+        check: 0xDF,
+        residue: 0x42,
+        name: "CRC-8/AUTOSAR",
+    };
+
+    /// The parameters of `crc8bluetooth`.
+    pub const CRC_8_BLUETOOTH: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0xA7,
+        init: 0x00,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+        check: 0x26,
+        residue: 0x00,
+        name: "CRC-8/BLUETOOTH",
+    };
+
+    /// The parameters of `crc8gsm_a`.
+    pub const CRC_8_GSM_A: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x1D,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x37,
+        residue: 0x00,
+        name: "CRC-8/GSM-A",
+    };
+
+    /// The parameters of `crc8gsm_b`.
+    pub const CRC_8_GSM_B: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x49,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0xFF,
+        check: 0x94,
+        residue: 0x53,
+        name: "CRC-8/GSM-B",
+    };
+
+    /// The parameters of `crc8hitag`.
+    pub const CRC_8_HITAG: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x1D,
+        init: 0xFF,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0xB4,
+        residue: 0x00,
+        name: "CRC-8/HITAG",
+    };
+
+    /// The parameters of `crc8lte`.
+    pub const CRC_8_LTE: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x9B,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0xEA,
+        residue: 0x00,
+        name: "CRC-8/LTE",
+    };
+
+    /// The parameters of `crc8mifare_mad`.
+    pub const CRC_8_MIFARE_MAD: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x1D,
+        init: 0xC7,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x99,
+        residue: 0x00,
+        name: "CRC-8/MIFARE-MAD",
+    };
+
+    /// The parameters of `crc8nrsc5`.
+    pub const CRC_8_NRSC_5: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x31,
+        init: 0xFF,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0xF7,
+        residue: 0x00,
+        name: "CRC-8/NRSC-5",
+    };
+
+    /// The parameters of `crc8opensafety`.
+    pub const CRC_8_OPENSAFETY: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x2F,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+        check: 0x3E,
+        residue: 0x00,
+        name: "CRC-8/OPENSAFETY",
+    };
+
+    /// The parameters of `crc8sae_j1850`.
+    pub const CRC_8_SAE_J1850: CRCParameters = CRCParameters {
+        width: 8,
+        poly: 0x1D,
+        init: 0xFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFF,
+        check: 0x4B,
+        residue: 0xC4,
+        name: "CRC-8/SAE-J1850",
+    };
+
+    /// The parameters of `crc10`.
+    pub const CRC_10_ATM: CRCParameters = CRCParameters {
+        width: 10,
+        poly: 0x233,
+        init: 0x000,
+        refin: false,
+        refout: false,
+        xorout: 0x000,
+        check: 0x199,
+        residue: 0x000,
+        name: "CRC-10/ATM",
+    };
+
+    /// The parameters of `crc10cdma2000`.
+    pub const CRC_10_CDMA2000: CRCParameters = CRCParameters {
+        width: 10,
+        poly: 0x3D9,
+        init: 0x3FF,
+        refin: false,
+        refout: false,
+        xorout: 0x000,
+        check: 0x233,
+        residue: 0x000,
+        name: "CRC-10/CDMA2000",
+    };
+
+    /// The parameters of `crc10gsm`.
+    pub const CRC_10_GSM: CRCParameters = CRCParameters {
+        width: 10,
+        poly: 0x175,
+        init: 0x000,
+        refin: false,
+        refout: false,
+        xorout: 0x3FF,
+        check: 0x12A,
+        residue: 0x0C6,
+        name: "CRC-10/GSM",
+    };
+
+    /// The parameters of `crc11`.
+    pub const CRC_11_FLEXRAY: CRCParameters = CRCParameters {
+        width: 11,
+        poly: 0x385,
+        init: 0x01A,
+        refin: false,
+        refout: false,
+        xorout: 0x000,
+        check: 0x5A3,
+        residue: 0x000,
+        name: "CRC-11/FLEXRAY",
+    };
+
+    /// The parameters of `crc11umts`.
+    pub const CRC_11_UMTS: CRCParameters = CRCParameters {
+        width: 11,
+        poly: 0x307,
+        init: 0x000,
+        refin: false,
+        refout: false,
+        xorout: 0x000,
+        check: 0x061,
+        residue: 0x000,
+        name: "CRC-11/UMTS",
+    };
+
+    /// The parameters of `crc12`.
+    pub const CRC_12_DECT: CRCParameters = CRCParameters {
+        width: 12,
+        poly: 0x80F,
+        init: 0x000,
+        refin: false,
+        refout: false,
+        xorout: 0x000,
+        check: 0xF5B,
+        residue: 0x000,
+        name: "CRC-12/DECT",
+    };
+
+    /// The parameters of `crc12umts`.
+    pub const CRC_12_UMTS: CRCParameters = CRCParameters {
+        width: 12,
+        poly: 0x80F,
+        init: 0x000,
+        refin: false,
+        refout: true,
+        xorout: 0x000,
+        check: 0xDAF,
+        residue: 0x000,
+        name: "CRC-12/UMTS",
+    };
+
+    /// The parameters of `crc12cdma2000`.
+    pub const CRC_12_CDMA2000: CRCParameters = CRCParameters {
+        width: 12,
+        poly: 0xF13,
+        init: 0xFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x000,
+        check: 0xD4D,
+        residue: 0x000,
+        name: "CRC-12/CDMA2000",
+    };
+
+    /// The parameters of `crc12gsm`.
+    pub const CRC_12_GSM: CRCParameters = CRCParameters {
+        width: 12,
+        poly: 0xD31,
+        init: 0x000,
+        refin: false,
+        refout: false,
+        xorout: 0xFFF,
+        check: 0xB34,
+        residue: 0x178,
+        name: "CRC-12/GSM",
+    };
+
+    /// The parameters of `crc13bbc`.
+    pub const CRC_13_BBC: CRCParameters = CRCParameters {
+        width: 13,
+        poly: 0x1CF5,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x04FA,
+        residue: 0x0000,
+        name: "CRC-13/BBC",
+    };
+
+    /// The parameters of `crc14darc`.
+    pub const CRC_14_DARC: CRCParameters = CRCParameters {
+        width: 14,
+        poly: 0x0805,
+        init: 0x0000,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0x082D,
+        residue: 0x0000,
+        name: "CRC-14/DARC",
+    };
+
+    /// The parameters of `crc14gsm`.
+    pub const CRC_14_GSM: CRCParameters = CRCParameters {
+        width: 14,
+        poly: 0x202D,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x3FFF,
+        check: 0x30AE,
+        residue: 0x031E,
+        name: "CRC-14/GSM",
+    };
+
+    /// The parameters of `crc15can`.
+    pub const CRC_15_CAN: CRCParameters = CRCParameters {
+        width: 15,
+        poly: 0x4599,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x059E,
+        residue: 0x0000,
+        name: "CRC-15/CAN",
+    };
+
+    /// The parameters of `crc15mpt1327`.
+    pub const CRC_15_MPT1327: CRCParameters = CRCParameters {
+        width: 15,
+        poly: 0x6815,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0001,
+        check: 0x2566,
+        residue: 0x6815,
+        name: "CRC-15/MPT1327",
+    };
+
+    /// The parameters of `crc16`.
+    pub const CRC_16_ARC: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8005,
+        init: 0x0000,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0xBB3D,
+        residue: 0x0000,
+        name: "CRC-16/ARC",
+    };
+
+    /// The parameters of `crc16ccitt_false`.
+    pub const CRC_16_IBM_3740: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0xFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x29B1,
+        residue: 0x0000,
+        name: "CRC-16/IBM-3740",
+    };
+
+    /// The parameters of `crc16aug_ccitt`.
+    pub const CRC_16_SPI_FUJITSU: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0x1D0F,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0xE5CC,
+        residue: 0x0000,
+        name: "CRC-16/SPI-FUJITSU",
+    };
+
+    /// The parameters of `crc16buypass`.
+    pub const CRC_16_UMTS: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8005,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0xFEE8,
+        residue: 0x0000,
+        name: "CRC-16/UMTS",
+    };
+
+    /// The parameters of `crc16cdma2000`.
+    pub const CRC_16_CDMA2000: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0xC867,
+        init: 0xFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x4C06,
+        residue: 0x0000,
+        name: "CRC-16/CDMA2000",
+    };
+
+    /// The parameters of `crc16dds_110`.
+    pub const CRC_16_DDS_110: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8005,
+        init: 0x800D,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x9ECF,
+        residue: 0x0000,
+        name: "CRC-16/DDS-110",
+    };
+
+    /// The parameters of `crc16dect_r`.
+    pub const CRC_16_DECT_R: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x0589,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0001,
+        check: 0x007E,
+        residue: 0x0589,
+        name: "CRC-16/DECT-R",
+    };
+
+    /// The parameters of `crc16dect_x`.
+    pub const CRC_16_DECT_X: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x0589,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x007F,
+        residue: 0x0000,
+        name: "CRC-16/DECT-X",
+    };
+
+    /// The parameters of `crc16dnp`.
+    pub const CRC_16_DNP: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x3D65,
+        init: 0x0000,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFF,
+        check: 0xEA82,
+        residue: 0x66C5,
+        name: "CRC-16/DNP",
+    };
+
+    /// The parameters of `crc16en_13757`.
+    pub const CRC_16_EN_13757: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x3D65,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFF,
+        check: 0xC2B7,
+        residue: 0xA366,
+        name: "CRC-16/EN-13757",
+    };
+
+    /// The parameters of `crc16genibus`.
+    pub const CRC_16_GENIBUS: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0xFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFF,
+        check: 0xD64E,
+        residue: 0x1D0F,
+        name: "CRC-16/GENIBUS",
+    };
+
+    /// The parameters of `crc16maxim`.
+    pub const CRC_16_MAXIM_DOW: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8005,
+        init: 0x0000,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFF,
+        check: 0x44C2,
+        residue: 0xB001,
+        name: "CRC-16/MAXIM-DOW",
+    };
+
+    /// The parameters of `crc16mcrf4cc`.
+    pub const CRC_16_MCRF4XX: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0xFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0x6F91,
+        residue: 0x0000,
+        name: "CRC-16/MCRF4XX",
+    };
+
+    /// The parameters of `crc16riello`.
+    pub const CRC_16_RIELLO: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0xB2AA,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0x63D0,
+        residue: 0x0000,
+        name: "CRC-16/RIELLO",
+    };
+
+    /// The parameters of `crc16t10_dif`.
+    pub const CRC_16_T10_DIF: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8BB7,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0xD0DB,
+        residue: 0x0000,
+        name: "CRC-16/T10-DIF",
+    };
+
+    /// The parameters of `crc16teledisk`.
+    pub const CRC_16_TELEDISK: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0xA097,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x0FB3,
+        residue: 0x0000,
+        name: "CRC-16/TELEDISK",
+    };
+
+    /// The parameters of `crc16tms13157`.
+    pub const CRC_16_TMS37157: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0x89EC,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0x26B1,
+        residue: 0x0000,
+        name: "CRC-16/TMS37157",
+    };
+
+    /// The parameters of `crc16usb`.
+    pub const CRC_16_USB: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8005,
+        init: 0xFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFF,
+        check: 0xB4C8,
+        residue: 0xB001,
+        name: "CRC-16/USB",
+    };
+
+    /// The parameters of `crc_a`.
+    pub const CRC_16_ISO_IEC_14443_3_A: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0xC6C6,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0xBF05,
+        residue: 0x0000,
+        name: "CRC-16/ISO-IEC-14443-3-A",
+    };
+
+    /// The parameters of `crc16kermit`.
+    pub const CRC_16_KERMIT: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0x0000,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0x2189,
+        residue: 0x0000,
+        name: "CRC-16/KERMIT",
+    };
+
+    /// The parameters of `crc16modbus`.
+    pub const CRC_16_MODBUS: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8005,
+        init: 0xFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0x4B37,
+        residue: 0x0000,
+        name: "CRC-16/MODBUS",
+    };
+
+    /// The parameters of `crc16_x25`.
+    pub const CRC_16_IBM_SDLC: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0xFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFF,
+        check: 0x906E,
+        residue: 0xF0B8,
+        name: "CRC-16/IBM-SDLC",
+    };
+
+    /// The parameters of `crc16xmodem`.
+    pub const CRC_16_XMODEM: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x31C3,
+        residue: 0x0000,
+        name: "CRC-16/XMODEM",
+    };
+
+    /// The parameters of `crc16cms`.
+    pub const CRC_16_CMS: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x8005,
+        init: 0xFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0xAEE7,
+        residue: 0x0000,
+        name: "CRC-16/CMS",
+    };
+
+    /// The parameters of `crc16gsm`.
+    pub const CRC_16_GSM: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1021,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFF,
+        check: 0xCE3C,
+        residue: 0x1D0F,
+        name: "CRC-16/GSM",
+    };
+
+    /// The parameters of `crc16lj1200`.
+    pub const CRC_16_LJ1200: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x6F63,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0xBDF4,
+        residue: 0x0000,
+        name: "CRC-16/LJ1200",
+    };
+
+    /// The parameters of `crc16m17`.
+    pub const CRC_16_M17: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x5935,
+        init: 0xFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x772B,
+        residue: 0x0000,
+        name: "CRC-16/M17",
+    };
+
+    /// The parameters of `crc16nrsc5`.
+    pub const CRC_16_NRSC_5: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x080B,
+        init: 0xFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+        check: 0xA066,
+        residue: 0x0000,
+        name: "CRC-16/NRSC-5",
+    };
+
+    /// The parameters of `crc16opensafety_a`.
+    pub const CRC_16_OPENSAFETY_A: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x5935,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x5D38,
+        residue: 0x0000,
+        name: "CRC-16/OPENSAFETY-A",
+    };
+
+    /// The parameters of `crc16opensafety_b`.
+    pub const CRC_16_OPENSAFETY_B: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x755B,
+        init: 0x0000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+        check: 0x20FE,
+        residue: 0x0000,
+        name: "CRC-16/OPENSAFETY-B",
+    };
+
+    /// The parameters of `crc16profibus`.
+    pub const CRC_16_PROFIBUS: CRCParameters = CRCParameters {
+        width: 16,
+        poly: 0x1DCF,
+        init: 0xFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFF,
+        check: 0xA819,
+        residue: 0xE394,
+        name: "CRC-16/PROFIBUS",
+    };
+
+    /// The parameters of `crc17can`.
+    pub const CRC_17_CAN_FD: CRCParameters = CRCParameters {
+        width: 17,
+        poly: 0x1685B,
+        init: 0x00000,
+        refin: false,
+        refout: false,
+        xorout: 0x00000,
+        check: 0x04F03,
+        residue: 0x00000,
+        name: "CRC-17/CAN-FD",
+    };
+
+    /// The parameters of `crc21can`.
+    pub const CRC_21_CAN_FD: CRCParameters = CRCParameters {
+        width: 21,
+        poly: 0x102899,
+        init: 0x000000,
+        refin: false,
+        refout: false,
+        xorout: 0x000000,
+        check: 0x0ED841,
+        residue: 0x000000,
+        name: "CRC-21/CAN-FD",
+    };
+
+    /// The parameters of `crc24`.
+    pub const CRC_24_OPENPGP: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x864CFB,
+        init: 0xB704CE,
+        refin: false,
+        refout: false,
+        xorout: 0x000000,
+        check: 0x21CF02,
+        residue: 0x000000,
+        name: "CRC-24/OPENPGP",
+    };
+
+    /// The parameters of `crc24ble`.
+    pub const CRC_24_BLE: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x00065B,
+        init: 0x555555,
+        refin: true,
+        refout: true,
+        xorout: 0x000000,
+        check: 0xC25A56,
+        residue: 0x000000,
+        name: "CRC-24/BLE",
+    };
+
+    /// The parameters of `crc24flexray_a`.
+    pub const CRC_24_FLEXRAY_A: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x5D6DCB,
+        init: 0xFEDCBA,
+        refin: false,
+        refout: false,
+        xorout: 0x000000,
+        check: 0x7979BD,
+        residue: 0x000000,
+        name: "CRC-24/FLEXRAY-A",
+    };
+
+    /// The parameters of `crc24flexray_b`.
+    pub const CRC_24_FLEXRAY_B: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x5D6DCB,
+        init: 0xABCDEF,
+        refin: false,
+        refout: false,
+        xorout: 0x000000,
+        check: 0x1F23B8,
+        residue: 0x000000,
+        name: "CRC-24/FLEXRAY-B",
+    };
+
+    /// The parameters of `crc24lte_a`.
+    pub const CRC_24_LTE_A: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x864CFB,
+        init: 0x000000,
+        refin: false,
+        refout: false,
+        xorout: 0x000000,
+        check: 0xCDE703,
+        residue: 0x000000,
+        name: "CRC-24/LTE-A",
+    };
+
+    /// The parameters of `crc24lte_b`.
+    pub const CRC_24_LTE_B: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x800063,
+        init: 0x000000,
+        refin: false,
+        refout: false,
+        xorout: 0x000000,
+        check: 0x23EF52,
+        residue: 0x000000,
+        name: "CRC-24/LTE-B",
+    };
+
+    /// The parameters of `crc24os9`.
+    pub const CRC_24_OS_9: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x800063,
+        init: 0xFFFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFFFF,
+        check: 0x200FA5,
+        residue: 0x800FE3,
+        name: "CRC-24/OS-9",
+    };
+
+    /// The parameters of `crc24interlaken`.
+    pub const CRC_24_INTERLAKEN: CRCParameters = CRCParameters {
+        width: 24,
+        poly: 0x328B63,
+        init: 0xFFFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFFFF,
+        check: 0xB4F3E6,
+        residue: 0x144E63,
+        name: "CRC-24/INTERLAKEN",
+    };
+
+    /// The parameters of `crc30cdma`.
+    pub const CRC_30_CDMA: CRCParameters = CRCParameters {
+        width: 30,
+        poly: 0x2030B9C7,
+        init: 0x3FFFFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x3FFFFFFF,
+        check: 0x04C34ABF,
+        residue: 0x34EFA55A,
+        name: "CRC-30/CDMA",
+    };
+
+    /// The parameters of `crc31philips`.
+    pub const CRC_31_PHILIPS: CRCParameters = CRCParameters {
+        width: 31,
+        poly: 0x04C11DB7,
+        init: 0x7FFFFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x7FFFFFFF,
+        check: 0x0CE9E46C,
+        residue: 0x4EAF26F1,
+        name: "CRC-31/PHILIPS",
+    };
+
+    /// The parameters of `crc32`.
+    pub const CRC_32_ISO_HDLC: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x04C11DB7,
+        init: 0xFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFFFFFF,
+        check: 0xCBF43926,
+        residue: 0xDEBB20E3,
+        name: "CRC-32/ISO-HDLC",
+    };
+
+    /// The parameters of `crc32bzip2`.
+    pub const CRC_32_BZIP2: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x04C11DB7,
+        init: 0xFFFFFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFFFFFF,
+        check: 0xFC891918,
+        residue: 0xC704DD7B,
+        name: "CRC-32/BZIP2",
+    };
+
+    /// The parameters of `crc32c`.
+    pub const CRC_32_ISCSI: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x1EDC6F41,
+        init: 0xFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFFFFFF,
+        check: 0xE3069283,
+        residue: 0xB798B438,
+        name: "CRC-32/ISCSI",
+    };
+
+    /// The parameters of `crc32d`.
+    pub const CRC_32_BASE91_D: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0xA833982B,
+        init: 0xFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFFFFFF,
+        check: 0x87315576,
+        residue: 0x45270551,
+        name: "CRC-32/BASE91-D",
+    };
+
+    /// The parameters of `crc32mpeg2`.
+    pub const CRC_32_MPEG_2: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x04C11DB7,
+        init: 0xFFFFFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x00000000,
+        check: 0x0376E6E7,
+        residue: 0x00000000,
+        name: "CRC-32/MPEG-2",
+    };
+
+    /// The parameters of `crc32posix`.
+    pub const CRC_32_CKSUM: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x04C11DB7,
+        init: 0x00000000,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFFFFFF,
+        check: 0x765E7680,
+        residue: 0xC704DD7B,
+        name: "CRC-32/CKSUM",
+    };
+
+    /// The parameters of `crc32q`.
+    pub const CRC_32_AIXM: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x814141AB,
+        init: 0x00000000,
+        refin: false,
+        refout: false,
+        xorout: 0x00000000,
+        check: 0x3010BF7F,
+        residue: 0x00000000,
+        name: "CRC-32/AIXM",
+    };
+
+    /// The parameters of `crc32jamcrc`.
+    pub const CRC_32_JAMCRC: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x04C11DB7,
+        init: 0xFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0x00000000,
+        check: 0x340BC6D9,
+        residue: 0x00000000,
+        name: "CRC-32/JAMCRC",
+    };
+
+    /// The parameters of `crc32xfer`.
+    pub const CRC_32_XFER: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x000000AF,
+        init: 0x00000000,
+        refin: false,
+        refout: false,
+        xorout: 0x00000000,
+        check: 0xBD0BE338,
+        residue: 0x00000000,
+        name: "CRC-32/XFER",
+    };
+
+    /// The parameters of `crc32autosar`.
+    pub const CRC_32_AUTOSAR: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0xF4ACFB13,
+        init: 0xFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFFFFFF,
+        check: 0x1697D06A,
+        residue: 0x904CDDBF,
+        name: "CRC-32/AUTOSAR",
+    };
+
+    /// The parameters of `crc32cd_rom_edc`.
+    pub const CRC_32_CD_ROM_EDC: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x8001801B,
+        init: 0x00000000,
+        refin: true,
+        refout: true,
+        xorout: 0x00000000,
+        check: 0x6EC2EDC4,
+        residue: 0x00000000,
+        name: "CRC-32/CD-ROM-EDC",
+    };
+
+    /// The parameters of `crc32mef`.
+    pub const CRC_32_MEF: CRCParameters = CRCParameters {
+        width: 32,
+        poly: 0x741B8CD7,
+        init: 0xFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0x00000000,
+        check: 0xD2C22F51,
+        residue: 0x00000000,
+        name: "CRC-32/MEF",
+    };
+
+    /// The parameters of `crc40gsm`.
+    pub const CRC_40_GSM: CRCParameters = CRCParameters {
+        width: 40,
+        poly: 0x0004820009,
+        init: 0x0000000000,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFFFFFFFF,
+        check: 0xD4164FC646,
+        residue: 0xC4FF8071FF,
+        name: "CRC-40/GSM",
+    };
+
+    /// The parameters of `crc64`.
+    pub const CRC_64_WE: CRCParameters = CRCParameters {
+        width: 64,
+        poly: 0x42F0E1EBA9EA3693,
+        init: 0xFFFFFFFFFFFFFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0xFFFFFFFFFFFFFFFF,
+        check: 0x62EC59E3F1A4F00A,
+        residue: 0xFCACBEBD5931A992,
+        name: "CRC-64/WE",
+    };
+
+    /// The parameters of `crc64iso`.
+    pub const CRC_64_GO_ISO: CRCParameters = CRCParameters {
+        width: 64,
+        poly: 0x000000000000001B,
+        init: 0xFFFFFFFFFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFFFFFFFFFFFFFF,
+        check: 0xB90956C775A41001,
+        residue: 0x5300000000000000,
+        name: "CRC-64/GO-ISO",
+    };
+
+    /// The parameters of `crc64jones`.
+    pub const CRC_64_REDIS: CRCParameters = CRCParameters {
+        width: 64,
+        poly: 0xAD93D23594C935A9,
+        init: 0x0000000000000000,
+        refin: true,
+        refout: true,
+        xorout: 0x0000000000000000,
+        check: 0xE9C6D914C4B8D9CA,
+        residue: 0x0000000000000000,
+        name: "CRC-64/REDIS",
+    };
+
+    /// The parameters of `crc64ecma182`.
+    pub const CRC_64_ECMA_182: CRCParameters = CRCParameters {
+        width: 64,
+        poly: 0x42F0E1EBA9EA3693,
+        init: 0x0000000000000000,
+        refin: false,
+        refout: false,
+        xorout: 0x0000000000000000,
+        check: 0x6C40DF5F0B497347,
+        residue: 0x0000000000000000,
+        name: "CRC-64/ECMA-182",
+    };
+
+    /// The parameters of `crc64ms`.
+    pub const CRC_64_MS: CRCParameters = CRCParameters {
+        width: 64,
+        poly: 0x259C84CBA6426349,
+        init: 0xFFFFFFFFFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0x0000000000000000,
+        check: 0x75D4B74F024ECEEA,
+        residue: 0x0000000000000000,
+        name: "CRC-64/MS",
+    };
+
+    /// The parameters of `crc64nvme`.
+    pub const CRC_64_NVME: CRCParameters = CRCParameters {
+        width: 64,
+        poly: 0xAD93D23594C93659,
+        init: 0xFFFFFFFFFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFFFFFFFFFFFFFF,
+        check: 0xAE8B14860A799888,
+        residue: 0xF310303B2B6F6E42,
+        name: "CRC-64/NVME",
+    };
+
+    /// The parameters of `crc64xz`.
+    pub const CRC_64_XZ: CRCParameters = CRCParameters {
+        width: 64,
+        poly: 0x42F0E1EBA9EA3693,
+        init: 0xFFFFFFFFFFFFFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFFFFFFFFFFFFFF,
+        check: 0x995DC9BBDF1939FA,
+        residue: 0x49958C9ABD7D353F,
+        name: "CRC-64/XZ",
+    };
+
+    /// The parameters of `crc82darc`.
+    pub const CRC_82_DARC: CRCParameters = CRCParameters {
+        width: 82,
+        poly: 0x0308C0111011401440411,
+        init: 0x000000000000000000000,
+        refin: true,
+        refout: true,
+        xorout: 0x000000000000000000000,
+        check: 0x09EA83F625023801FD612,
+        residue: 0x000000000000000000000,
+        name: "CRC-82/DARC",
+    };
+}
+
+impl CRCParametersBuilder {
+    /// Create a builder by providing the length of bits and the (non-reflected) expression. The initial value and the final xor value are `0` and nothing is reflected by default.
+    #[inline]
+    pub const fn new(width: u8, poly: u128) -> CRCParametersBuilder {
+        debug_assert!(width <= 128 && width > 0);
+
+        CRCParametersBuilder {
+            width,
+            poly,
+            init: 0,
+            refin: false,
+            refout: false,
+            xorout: 0,
+            name: "",
+        }
+    }
+
+    #[inline]
+    pub const fn poly(mut self, poly: u128) -> CRCParametersBuilder {
+        self.poly = poly;
+        self
+    }
+
+    #[inline]
+    pub const fn init(mut self, init: u128) -> CRCParametersBuilder {
+        self.init = init;
+        self
+    }
+
+    #[inline]
+    pub const fn refin(mut self, refin: bool) -> CRCParametersBuilder {
+        self.refin = refin;
+        self
+    }
+
+    #[inline]
+    pub const fn refout(mut self, refout: bool) -> CRCParametersBuilder {
+        self.refout = refout;
+        self
+    }
+
+    /// Set the reflection of both the input and the output.
+    #[inline]
+    pub const fn reflect(self, reflect: bool) -> CRCParametersBuilder {
+        self.refin(reflect).refout(reflect)
+    }
+
+    #[inline]
+    pub const fn xorout(mut self, xorout: u128) -> CRCParametersBuilder {
+        self.xorout = xorout;
+        self
+    }
+
+    #[inline]
+    pub const fn name(mut self, name: &'static str) -> CRCParametersBuilder {
+        self.name = name;
+        self
+    }
+
+    /// Build the parameters like `build`, but return an error instead of masking the parameters if they are invalid, e.g. if the expression, the initial value or the final xor value is wider than the length of bits.
+    #[inline]
+    pub const fn try_build(self) -> Result<CRCParameters, CRCError> {
+        if let Err(err) = check_parameters(self.poly, self.width, 128, self.init, self.xorout, false) {
+            return Err(err);
+        }
+
+        Ok(self.build())
+    }
+
+    /// Build the parameters, whose check value and residue are computed bit by bit, so it can be used at compile time. The bits of the expression, the initial value and the final xor value which are out of the length of bits are silently discarded, so use `try_build` to check the parameters instead.
+    pub const fn build(self) -> CRCParameters {
+        let high_bit = 1u128 << (self.width - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+
+        let poly = self.poly & mask;
+        let init = self.init & mask;
+        let xorout = self.xorout & mask;
+
+        let mut sum = init;
+
+        let mut i = 0;
+
+        while i < CHECK_INPUT.len() {
+            let n = if self.refin {
+                CHECK_INPUT[i].reverse_bits()
+            } else {
+                CHECK_INPUT[i]
+            };
+
+            let mut j = 8;
+
+            while j > 0 {
+                j -= 1;
+
+                let carry = (sum & high_bit != 0) != ((n >> j) & 1 != 0);
+
+                sum = (sum << 1) & mask;
+
+                if carry {
+                    sum ^= poly;
+                }
+            }
+
+            i += 1;
+        }
+
+        if self.refout {
+            sum = reflect(sum, self.width);
+        }
+
+        let check = sum ^ xorout;
+
+        let residue = residue(self.width, poly, self.refout, xorout);
+
+        CRCParameters {
+            width: self.width,
+            poly,
+            init,
+            refin: self.refin,
+            refout: self.refout,
+            xorout,
+            check,
+            residue,
+            name: self.name,
+        }
+    }
+}
+
+/// Compute the residue of an algorithm bit by bit. The expression and the final xor value are never reflected and must be within the length of bits.
+pub(crate) const fn residue(width: u8, poly: u128, refout: bool, xorout: u128) -> u128 {
+    let high_bit = 1u128 << (width - 1);
+    let mask = ((high_bit - 1) << 1) | 1;
+
+    // an error-free codeword leaves the register with `xorout` multiplied by x^width
+    let mut residue = if refout {
+        reflect(xorout, width)
+    } else {
+        xorout
+    };
+
+    let mut i = 0;
+
+    while i < width {
+        let carry = residue & high_bit != 0;
+
+        residue = (residue << 1) & mask;
+
+        if carry {
+            residue ^= poly;
+        }
+
+        i += 1;
+    }
+
+    if refout {
+        residue = reflect(residue, width);
+    }
+
+    residue
+}
+
+#[inline]
+const fn reflect(n: u128, width: u8) -> u128 {
+    n.reverse_bits() >> (128 - width)
+}
@@ -0,0 +1,164 @@
+//! Carry-less multiplication (PCLMULQDQ) folding for CRCs up to 64 bits, which is described in Intel's white paper "Fast CRC Computation for Generic Polynomials Using PCLMULQDQ Instruction".
+//!
+//! The data (with the current CRC register xored into its head) is folded, 64 bytes at a time, into a 128-bit value which has the same remainder. The folded value and the remaining data then go through the lookup tables with a zero register, so no Barrett reduction is needed and any polynomial can be used.
+
+use core::arch::x86_64::*;
+
+/// The minimum length of data which is worth folding.
+pub(crate) const MIN_LENGTH: usize = 128;
+
+/// The multipliers of folding a 128-bit value forward by 512, 384, 256 and 128 bits, stored as `[low qword, high qword]`.
+#[derive(Clone, Copy)]
+pub(crate) struct FoldingConstants {
+    reflect: bool,
+    k512: [u64; 2],
+    k384: [u64; 2],
+    k256: [u64; 2],
+    k128: [u64; 2],
+}
+
+impl FoldingConstants {
+    /// Derive the folding constants from a (non-reflected) polynomial whose length of bits is `bits`.
+    pub(crate) fn new(poly: u64, bits: u8, reflect: bool) -> FoldingConstants {
+        let high_bit = 1u64 << (bits - 1);
+        let mask = ((high_bit - 1) << 1) | 1;
+        let poly = poly & mask;
+
+        // x^(64 * j) mod P for j = 2..=9 (or x^(64 * j - 1) mod P for reflected CRCs, in order to compensate the one-bit shift of multiplying bit-reversed operands)
+        let mut remainders = [0u64; 10];
+
+        let mut r = 1u64;
+
+        for k in 1..=576u32 {
+            let carry = r & high_bit != 0;
+
+            r = (r << 1) & mask;
+
+            if carry {
+                r ^= poly;
+            }
+
+            let e = if reflect { k + 1 } else { k };
+
+            if e % 64 == 0 {
+                remainders[(e / 64) as usize] = r;
+            }
+        }
+
+        let pair = |d: usize| {
+            let j = d / 64;
+
+            if reflect {
+                [remainders[j + 1].reverse_bits(), remainders[j].reverse_bits()]
+            } else {
+                [remainders[j], remainders[j + 1]]
+            }
+        };
+
+        FoldingConstants {
+            reflect,
+            k512: pair(512),
+            k384: pair(384),
+            k256: pair(256),
+            k128: pair(128),
+        }
+    }
+}
+
+/// Check whether PCLMULQDQ (and SSSE3) can be used on the current CPU.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn is_available() -> bool {
+    std::is_x86_feature_detected!("pclmulqdq") && std::is_x86_feature_detected!("ssse3")
+}
+
+/// Check whether PCLMULQDQ (and SSSE3) can be used on the current CPU.
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn is_available() -> bool {
+    cfg!(all(target_feature = "pclmulqdq", target_feature = "ssse3"))
+}
+
+#[inline]
+#[target_feature(enable = "ssse3")]
+unsafe fn swap_if_needed(x: __m128i, reflect: bool) -> __m128i {
+    if reflect {
+        x
+    } else {
+        _mm_shuffle_epi8(x, _mm_set_epi8(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15))
+    }
+}
+
+#[inline]
+#[target_feature(enable = "ssse3")]
+unsafe fn load(data: &[u8], reflect: bool) -> __m128i {
+    debug_assert!(data.len() >= 16);
+
+    swap_if_needed(_mm_loadu_si128(data.as_ptr() as *const __m128i), reflect)
+}
+
+#[inline]
+#[target_feature(enable = "sse2")]
+unsafe fn constant(k: [u64; 2]) -> __m128i {
+    _mm_set_epi64x(k[1] as i64, k[0] as i64)
+}
+
+#[inline]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn fold_by(x: __m128i, k: __m128i) -> __m128i {
+    _mm_xor_si128(_mm_clmulepi64_si128(x, k, 0x00), _mm_clmulepi64_si128(x, k, 0x11))
+}
+
+/// Fold `data` (whose first 8 bytes are xored with `head`) into 16 bytes. Return the folded bytes and the remaining data (less than 16 bytes), which must be digested in order with a zero CRC register.
+///
+/// The caller must make sure that PCLMULQDQ and SSSE3 are available and `data` has at least `MIN_LENGTH` bytes.
+#[target_feature(enable = "pclmulqdq", enable = "ssse3")]
+pub(crate) unsafe fn fold<'a>(constants: &FoldingConstants, head: [u8; 8], data: &'a [u8]) -> ([u8; 16], &'a [u8]) {
+    debug_assert!(data.len() >= MIN_LENGTH);
+
+    let reflect = constants.reflect;
+
+    let (first, rest) = data.split_at(64);
+
+    let mut block = [0u8; 64];
+
+    block.copy_from_slice(first);
+
+    for (b, h) in block.iter_mut().zip(head.iter()) {
+        *b ^= h;
+    }
+
+    let mut x0 = load(&block[0..], reflect);
+    let mut x1 = load(&block[16..], reflect);
+    let mut x2 = load(&block[32..], reflect);
+    let mut x3 = load(&block[48..], reflect);
+
+    let k512 = constant(constants.k512);
+
+    let mut groups = rest.chunks_exact(64);
+
+    for group in &mut groups {
+        x0 = _mm_xor_si128(fold_by(x0, k512), load(&group[0..], reflect));
+        x1 = _mm_xor_si128(fold_by(x1, k512), load(&group[16..], reflect));
+        x2 = _mm_xor_si128(fold_by(x2, k512), load(&group[32..], reflect));
+        x3 = _mm_xor_si128(fold_by(x3, k512), load(&group[48..], reflect));
+    }
+
+    let k128 = constant(constants.k128);
+
+    let mut x = _mm_xor_si128(fold_by(x0, constant(constants.k384)), fold_by(x1, constant(constants.k256)));
+    x = _mm_xor_si128(x, fold_by(x2, k128));
+    x = _mm_xor_si128(x, x3);
+
+    let mut chunks = groups.remainder().chunks_exact(16);
+
+    for chunk in &mut chunks {
+        x = _mm_xor_si128(fold_by(x, k128), load(chunk, reflect));
+    }
+
+    let mut folded = [0u8; 16];
+
+    _mm_storeu_si128(folded.as_mut_ptr() as *mut __m128i, swap_if_needed(x, reflect));
+
+    (folded, chunks.remainder())
+}
@@ -0,0 +1,407 @@
+//! Reverse-engineer the parameters of CRC algorithms from sample messages and their CRC values, in the spirit of CRC RevEng.
+//!
+//! The CRC values of two messages of the same length differ by the CRC value (with a zero initial value and a zero final xor value) of the difference of the messages, so the expression `P` (with its highest term) divides `D(x) * x^w + R(x)`, where `D` and `R` are the differences of the messages and their CRC values. The expression is derived from the GCD of these polynomials, and then the initial value and the final xor value are solved as linear equations over GF(2).
+
+use alloc::vec::Vec;
+
+use {CRC, CRCParameters, CRCParametersBuilder};
+
+/// The maximum degree of the cofactors which are tried when the GCD is wider than the CRC.
+const MAX_COFACTOR_DEGREE: usize = 16;
+
+/// The maximum number of bits of the initial value which cannot be determined by the samples, whose values are all tried. If there are more of them, only zeros or ones are tried.
+const MAX_FREE_BITS: usize = 8;
+
+/// A polynomial over GF(2) whose bit `i` is the coefficient of `x^i`. It never has leading zero words.
+#[derive(Clone)]
+struct Polynomial(Vec<u64>);
+
+impl Polynomial {
+    #[inline]
+    fn new() -> Polynomial {
+        Polynomial(Vec::new())
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Polynomial {
+        let mut polynomial = Polynomial(vec![n as u64, (n >> 64) as u64]);
+
+        polynomial.trim();
+
+        polynomial
+    }
+
+    /// The polynomial of a message whose bits are processed from the most significant bit of the first byte.
+    fn from_message(message: &[u8], refin: bool) -> Polynomial {
+        let mut words = vec![0u64; message.len().div_ceil(8)];
+
+        for (i, byte) in message.iter().rev().enumerate() {
+            let byte = if refin {
+                byte.reverse_bits()
+            } else {
+                *byte
+            };
+
+            words[i / 8] |= (byte as u64) << (i % 8 * 8);
+        }
+
+        let mut polynomial = Polynomial(words);
+
+        polynomial.trim();
+
+        polynomial
+    }
+
+    #[inline]
+    fn to_u128(&self) -> u128 {
+        self.0.iter().take(2).enumerate().fold(0, |n, (i, word)| n | ((*word as u128) << (64 * i)))
+    }
+
+    #[inline]
+    fn trim(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    #[inline]
+    fn degree(&self) -> Option<usize> {
+        self.0.last().map(|word| (self.0.len() - 1) * 64 + 63 - word.leading_zeros() as usize)
+    }
+
+    #[inline]
+    fn flip(&mut self, i: usize) {
+        if self.0.len() <= i / 64 {
+            self.0.resize(i / 64 + 1, 0);
+        }
+
+        self.0[i / 64] ^= 1 << (i % 64);
+
+        self.trim();
+    }
+
+    /// `self += other * x^shift`
+    fn xor_shifted(&mut self, other: &Polynomial, shift: usize) {
+        let (words, bits) = (shift / 64, shift % 64);
+
+        let length = other.0.len() + words + 1;
+
+        if self.0.len() < length {
+            self.0.resize(length, 0);
+        }
+
+        for (i, word) in other.0.iter().enumerate() {
+            self.0[i + words] ^= word << bits;
+
+            if bits > 0 {
+                self.0[i + words + 1] ^= word >> (64 - bits);
+            }
+        }
+
+        self.trim();
+    }
+
+    /// Divide by a non-zero polynomial. Return the quotient and the remainder.
+    fn div_rem(mut self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        let d = divisor.degree().unwrap();
+
+        let mut quotient = Polynomial::new();
+
+        while let Some(n) = self.degree() {
+            if n < d {
+                break;
+            }
+
+            quotient.flip(n - d);
+            self.xor_shifted(divisor, n - d);
+        }
+
+        (quotient, self)
+    }
+
+    fn gcd(mut a: Polynomial, mut b: Polynomial) -> Polynomial {
+        while b.degree().is_some() {
+            let (_, r) = a.div_rem(&b);
+
+            a = b;
+            b = r;
+        }
+
+        a
+    }
+}
+
+/// Coefficients of the final xor value and the initial value, and the constant term of a linear equation.
+type Equation = ([u128; 2], bool);
+
+#[inline]
+fn reflect(n: u128, width: u8) -> u128 {
+    n.reverse_bits() >> (128 - width)
+}
+
+/// Find all algorithms whose CRC values of the messages are the given ones. See `CRC::reverse_engineer`.
+pub(crate) fn search(samples: &[(&[u8], u128)], width: Option<u8>) -> Vec<CRC> {
+    let mut results: Vec<(CRCParameters, CRC)> = Vec::new();
+
+    let widths = match width {
+        Some(width) => width..=width,
+        None => {
+            let min_width = samples.iter().map(|(_, crc)| 128 - crc.leading_zeros() as u8).max().unwrap_or(0).max(1);
+
+            min_width..=128
+        }
+    };
+
+    for width in widths {
+        if width == 0 || width > 128 {
+            continue;
+        }
+
+        for &(refin, refout) in [(true, true), (false, false), (true, false), (false, true)].iter() {
+            for poly in search_polys(samples, width, refin, refout) {
+                for (init, xorout) in solve_init_xorout(samples, width, poly, refin, refout) {
+                    let parameters = CRCParametersBuilder::new(width, poly).init(init).refin(refin).refout(refout).xorout(xorout).build();
+
+                    if results.iter().any(|(p, _)| *p == parameters) {
+                        continue;
+                    }
+
+                    let crc = CRC::create_crc_with_parameters(&parameters);
+
+                    if samples.iter().all(|(message, value)| get_crc_u128(&crc, message) == *value) {
+                        results.push((parameters, crc));
+                    }
+                }
+            }
+        }
+    }
+
+    results.into_iter().map(|(_, crc)| crc).collect()
+}
+
+fn get_crc_u128(crc: &CRC, message: &[u8]) -> u128 {
+    let mut crc = crc.clone();
+
+    crc.digest(message);
+
+    crc.get_crc_u128()
+}
+
+/// Find the expressions (without their highest terms and never reflected) which can be used by the samples.
+fn search_polys(samples: &[(&[u8], u128)], width: u8, refin: bool, refout: bool) -> Vec<u128> {
+    let w = width as usize;
+
+    if samples.iter().any(|(_, crc)| w < 128 && crc >> w != 0) {
+        return Vec::new();
+    }
+
+    let crc = |value: u128| {
+        if refout {
+            reflect(value, width)
+        } else {
+            value
+        }
+    };
+
+    let mut gcd: Option<Polynomial> = None;
+
+    for (i, (message_a, crc_a)) in samples.iter().enumerate() {
+        if let Some((message_b, crc_b)) = samples[..i].iter().find(|(message, _)| message.len() == message_a.len()) {
+            let difference: Vec<u8> = message_a.iter().zip(message_b.iter()).map(|(a, b)| a ^ b).collect();
+
+            let mut polynomial = Polynomial::from_u128(crc(*crc_a) ^ crc(*crc_b));
+
+            polynomial.xor_shifted(&Polynomial::from_message(&difference, refin), w);
+
+            if polynomial.degree().is_none() {
+                continue;
+            }
+
+            gcd = Some(match gcd {
+                Some(gcd) => Polynomial::gcd(gcd, polynomial),
+                None => polynomial,
+            });
+        }
+    }
+
+    let gcd = match gcd {
+        Some(gcd) => gcd,
+        None => return Vec::new(),
+    };
+
+    let degree = gcd.degree().unwrap();
+
+    let mut polys = Vec::new();
+
+    let mut push = |p: &Polynomial| {
+        // the expression must have both its highest term and its lowest term
+        if p.degree() == Some(w) && p.0[0] & 1 == 1 {
+            let mut p = p.clone();
+
+            p.flip(w);
+
+            polys.push(p.to_u128());
+        }
+    };
+
+    if degree == w {
+        push(&gcd);
+    } else if degree > w && degree - w <= MAX_COFACTOR_DEGREE {
+        let k = degree - w;
+
+        for low in 0..(1u32 << k) {
+            let cofactor = Polynomial::from_u128((1u128 << k) | low as u128);
+
+            let (quotient, remainder) = gcd.clone().div_rem(&cofactor);
+
+            if remainder.degree().is_none() {
+                push(&quotient);
+            }
+        }
+    }
+
+    polys
+}
+
+/// Solve the initial values and the final xor values for an expression. If they cannot be told apart (e.g. all messages have the same length), only some of the solutions are returned.
+fn solve_init_xorout(samples: &[(&[u8], u128)], width: u8, poly: u128, refin: bool, refout: bool) -> Vec<(u128, u128)> {
+    let w = width as usize;
+    let mask = u128::MAX >> (128 - width);
+    let top = 1u128 << (width - 1);
+
+    let multiply_x = |register: u128| {
+        let carry = register & top != 0;
+
+        let register = (register << 1) & mask;
+
+        if carry {
+            register ^ poly
+        } else {
+            register
+        }
+    };
+
+    let mut equations: Vec<Equation> = Vec::with_capacity(samples.len() * w);
+
+    for (message, crc) in samples.iter() {
+        // M(x) * x^w mod P
+        let mut register = 0u128;
+
+        for byte in message.iter() {
+            let byte = if refin {
+                byte.reverse_bits()
+            } else {
+                *byte
+            };
+
+            for i in (0..8).rev() {
+                register = multiply_x(register ^ (((byte >> i) as u128 & 1) << (width - 1)));
+            }
+        }
+
+        let crc = if refout {
+            reflect(*crc, width)
+        } else {
+            *crc
+        };
+
+        let constant = crc ^ register;
+
+        // the columns of the initial value, x^(k + 8n) mod P
+        let mut column = 1u128;
+
+        for _ in 0..message.len() * 8 {
+            column = multiply_x(column);
+        }
+
+        let mut columns = Vec::with_capacity(w);
+
+        for _ in 0..w {
+            columns.push(column);
+
+            column = multiply_x(column);
+        }
+
+        for j in 0..w {
+            let init_coefficients = columns.iter().enumerate().fold(0u128, |n, (k, column)| n | (((column >> j) & 1) << k));
+
+            equations.push(([1 << j, init_coefficients], (constant >> j) & 1 == 1));
+        }
+    }
+
+    // Gauss-Jordan elimination, with the columns of the final xor value first so that the free variables are in the initial value
+    let mut pivots = Vec::with_capacity(w * 2);
+
+    for column in 0..(w * 2) {
+        let (word, bit) = (column / w, column % w);
+        let rank = pivots.len();
+
+        if let Some(i) = (rank..equations.len()).find(|&i| (equations[i].0[word] >> bit) & 1 == 1) {
+            equations.swap(rank, i);
+
+            let pivot = equations[rank];
+
+            for (i, equation) in equations.iter_mut().enumerate() {
+                if i != rank && (equation.0[word] >> bit) & 1 == 1 {
+                    equation.0[0] ^= pivot.0[0];
+                    equation.0[1] ^= pivot.0[1];
+                    equation.1 ^= pivot.1;
+                }
+            }
+
+            pivots.push(column);
+        }
+    }
+
+    if equations[pivots.len()..].iter().any(|equation| equation.1) {
+        return Vec::new();
+    }
+
+    let mut free = [mask, mask];
+
+    for column in pivots.iter() {
+        free[column / w] &= !(1 << (column % w));
+    }
+
+    let free_columns: Vec<usize> = (0..(w * 2)).filter(|column| (free[column / w] >> (column % w)) & 1 == 1).collect();
+
+    let assignments: Vec<[u128; 2]> = if free_columns.len() <= MAX_FREE_BITS {
+        (0..(1u32 << free_columns.len()))
+            .map(|n| {
+                let mut assignment = [0, 0];
+
+                for (i, column) in free_columns.iter().enumerate() {
+                    if (n >> i) & 1 == 1 {
+                        assignment[column / w] |= 1 << (column % w);
+                    }
+                }
+
+                assignment
+            })
+            .collect()
+    } else {
+        vec![[0, 0], free]
+    };
+
+    assignments
+        .into_iter()
+        .map(|mut solution| {
+
+            for (equation, column) in equations.iter().zip(pivots.iter()) {
+                let parity = ((equation.0[0] & solution[0]).count_ones() + (equation.0[1] & solution[1]).count_ones()) % 2 == 1;
+
+                if equation.1 ^ parity {
+                    solution[column / w] |= 1 << (column % w);
+                }
+            }
+
+            let xorout = if refout {
+                reflect(solution[0], width)
+            } else {
+                solution[0]
+            };
+
+            (solution[1], xorout)
+        })
+        .collect()
+}
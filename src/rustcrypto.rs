@@ -0,0 +1,274 @@
+//! Implementations of the traits of the RustCrypto `digest` crate, so the built-in algorithms can be used wherever a `Digest` is accepted.
+//!
+//! Every built-in algorithm has a zero-sized type named after its function, e.g. `CRC32C` for `crc32c`, which is used as the type parameter of `CRCDigest`.
+
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+
+use digest::consts::{U1, U2, U3, U4, U5, U8, U11};
+use digest::generic_array::ArrayLength;
+use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+use {CRCu8, CRCu16, CRCu32, CRCu64, CRCu128, Endian};
+
+/// A built-in algorithm which can be used by `CRCDigest`.
+pub trait CRCAlgorithm {
+    /// The length of the CRC value in bytes.
+    type OutputSize: ArrayLength<u8> + 'static;
+
+    /// The CRC type of this algorithm, e.g. `CRCu32` for `CRC32C`.
+    type Crc: Clone;
+
+    /// Create a CRC instance of this algorithm.
+    fn create() -> Self::Crc;
+
+    /// Digest some data.
+    fn digest(crc: &mut Self::Crc, data: &[u8]);
+
+    /// Reset the CRC instance to its initial state.
+    fn reset(crc: &mut Self::Crc);
+
+    /// Write the big-endian bytes of the CRC value, whose length is `OutputSize`.
+    fn write_crc(crc: &Self::Crc, out: &mut [u8]);
+}
+
+macro_rules! algorithms {
+    ($($name:ident, $function:ident, $crc:ident, $size:ty;)*) => {
+        $(
+            #[doc = concat!("The algorithm of `", stringify!($crc), "::", stringify!($function), "`.")]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub struct $name;
+
+            impl CRCAlgorithm for $name {
+                type OutputSize = $size;
+                type Crc = $crc;
+
+                #[inline]
+                fn create() -> $crc {
+                    $crc::$function()
+                }
+
+                #[inline]
+                fn digest(crc: &mut $crc, data: &[u8]) {
+                    crc.digest(data);
+                }
+
+                #[inline]
+                fn reset(crc: &mut $crc) {
+                    crc.reset();
+                }
+
+                #[inline]
+                fn write_crc(crc: &$crc, out: &mut [u8]) {
+                    crc.write_crc(out, Endian::Big);
+                }
+            }
+        )*
+    };
+}
+
+algorithms! {
+    CRC3Gsm, crc3gsm, CRCu8, U1;
+    CRC3Rohc, crc3rohc, CRCu8, U1;
+    CRC4Itu, crc4itu, CRCu8, U1;
+    CRC4Interlaken, crc4interlaken, CRCu8, U1;
+    CRC5Epc, crc5epc, CRCu8, U1;
+    CRC5Itu, crc5itu, CRCu8, U1;
+    CRC5Usb, crc5usb, CRCu8, U1;
+    CRC5EpcC1g2, crc5epc_c1g2, CRCu8, U1;
+    CRC6Cdma2000A, crc6cdma2000_a, CRCu8, U1;
+    CRC6Cdma2000B, crc6cdma2000_b, CRCu8, U1;
+    CRC6Darc, crc6darc, CRCu8, U1;
+    CRC6Gsm, crc6gsm, CRCu8, U1;
+    CRC6Itu, crc6itu, CRCu8, U1;
+    CRC7, crc7, CRCu8, U1;
+    CRC7Umts, crc7umts, CRCu8, U1;
+    CRC7Rohc, crc7rohc, CRCu8, U1;
+    CRC8, crc8, CRCu8, U1;
+    CRC8Cdma2000, crc8cdma2000, CRCu8, U1;
+    CRC8Darc, crc8darc, CRCu8, U1;
+    CRC8DvbS2, crc8dvb_s2, CRCu8, U1;
+    CRC8Ebu, crc8ebu, CRCu8, U1;
+    CRC8Icode, crc8icode, CRCu8, U1;
+    CRC8Itu, crc8itu, CRCu8, U1;
+    CRC8Maxim, crc8maxim, CRCu8, U1;
+    CRC8Rohc, crc8rohc, CRCu8, U1;
+    CRC8Wcdma, crc8wcdma, CRCu8, U1;
+    CRC8Autosar, crc8autosar, CRCu8, U1;
+    CRC8Bluetooth, crc8bluetooth, CRCu8, U1;
+    CRC8GsmA, crc8gsm_a, CRCu8, U1;
+    CRC8GsmB, crc8gsm_b, CRCu8, U1;
+    CRC8Hitag, crc8hitag, CRCu8, U1;
+    CRC8Lte, crc8lte, CRCu8, U1;
+    CRC8MifareMad, crc8mifare_mad, CRCu8, U1;
+    CRC8Nrsc5, crc8nrsc5, CRCu8, U1;
+    CRC8Opensafety, crc8opensafety, CRCu8, U1;
+    CRC8SaeJ1850, crc8sae_j1850, CRCu8, U1;
+    CRC10, crc10, CRCu16, U2;
+    CRC10Cdma2000, crc10cdma2000, CRCu16, U2;
+    CRC10Gsm, crc10gsm, CRCu16, U2;
+    CRC11, crc11, CRCu16, U2;
+    CRC11Umts, crc11umts, CRCu16, U2;
+    CRC12, crc12, CRCu16, U2;
+    CRC12Umts, crc12umts, CRCu16, U2;
+    CRC12Cdma2000, crc12cdma2000, CRCu16, U2;
+    CRC12Gsm, crc12gsm, CRCu16, U2;
+    CRC13Bbc, crc13bbc, CRCu16, U2;
+    CRC14Darc, crc14darc, CRCu16, U2;
+    CRC14Gsm, crc14gsm, CRCu16, U2;
+    CRC15Can, crc15can, CRCu16, U2;
+    CRC15Mpt1327, crc15mpt1327, CRCu16, U2;
+    CRC16, crc16, CRCu16, U2;
+    CRC16CcittFalse, crc16ccitt_false, CRCu16, U2;
+    CRC16AugCcitt, crc16aug_ccitt, CRCu16, U2;
+    CRC16Buypass, crc16buypass, CRCu16, U2;
+    CRC16Cdma2000, crc16cdma2000, CRCu16, U2;
+    CRC16Dds110, crc16dds_110, CRCu16, U2;
+    CRC16DectR, crc16dect_r, CRCu16, U2;
+    CRC16DectX, crc16dect_x, CRCu16, U2;
+    CRC16Dnp, crc16dnp, CRCu16, U2;
+    CRC16En13757, crc16en_13757, CRCu16, U2;
+    CRC16Genibus, crc16genibus, CRCu16, U2;
+    CRC16Maxim, crc16maxim, CRCu16, U2;
+    CRC16Mcrf4cc, crc16mcrf4cc, CRCu16, U2;
+    CRC16Riello, crc16riello, CRCu16, U2;
+    CRC16T10Dif, crc16t10_dif, CRCu16, U2;
+    CRC16Teledisk, crc16teledisk, CRCu16, U2;
+    CRC16Tms13157, crc16tms13157, CRCu16, U2;
+    CRC16Usb, crc16usb, CRCu16, U2;
+    CRCA, crc_a, CRCu16, U2;
+    CRC16Kermit, crc16kermit, CRCu16, U2;
+    CRC16Modbus, crc16modbus, CRCu16, U2;
+    CRC16X25, crc16_x25, CRCu16, U2;
+    CRC16IbmSdlc, crc16ibm_sdlc, CRCu16, U2;
+    CRC16Xmodem, crc16xmodem, CRCu16, U2;
+    CRC16Cms, crc16cms, CRCu16, U2;
+    CRC16Gsm, crc16gsm, CRCu16, U2;
+    CRC16Lj1200, crc16lj1200, CRCu16, U2;
+    CRC16M17, crc16m17, CRCu16, U2;
+    CRC16Nrsc5, crc16nrsc5, CRCu16, U2;
+    CRC16OpensafetyA, crc16opensafety_a, CRCu16, U2;
+    CRC16OpensafetyB, crc16opensafety_b, CRCu16, U2;
+    CRC16Profibus, crc16profibus, CRCu16, U2;
+    CRC17Can, crc17can, CRCu32, U3;
+    CRC21Can, crc21can, CRCu32, U3;
+    CRC24, crc24, CRCu32, U3;
+    CRC24Ble, crc24ble, CRCu32, U3;
+    CRC24FlexrayA, crc24flexray_a, CRCu32, U3;
+    CRC24FlexrayB, crc24flexray_b, CRCu32, U3;
+    CRC24LteA, crc24lte_a, CRCu32, U3;
+    CRC24LteB, crc24lte_b, CRCu32, U3;
+    CRC24Os9, crc24os9, CRCu32, U3;
+    CRC24Interlaken, crc24interlaken, CRCu32, U3;
+    CRC30Cdma, crc30cdma, CRCu32, U4;
+    CRC31Philips, crc31philips, CRCu32, U4;
+    CRC32, crc32, CRCu32, U4;
+    CRC32Mhash, crc32mhash, CRCu32, U4;
+    CRC32Bzip2, crc32bzip2, CRCu32, U4;
+    CRC32C, crc32c, CRCu32, U4;
+    CRC32Iscsi, crc32iscsi, CRCu32, U4;
+    CRC32D, crc32d, CRCu32, U4;
+    CRC32Mpeg2, crc32mpeg2, CRCu32, U4;
+    CRC32Posix, crc32posix, CRCu32, U4;
+    CRC32Q, crc32q, CRCu32, U4;
+    CRC32Jamcrc, crc32jamcrc, CRCu32, U4;
+    CRC32Xfer, crc32xfer, CRCu32, U4;
+    CRC32Autosar, crc32autosar, CRCu32, U4;
+    CRC32CdRomEdc, crc32cd_rom_edc, CRCu32, U4;
+    CRC32Mef, crc32mef, CRCu32, U4;
+    CRC40Gsm, crc40gsm, CRCu64, U5;
+    CRC64, crc64, CRCu64, U8;
+    CRC64We, crc64we, CRCu64, U8;
+    CRC64Iso, crc64iso, CRCu64, U8;
+    CRC64GoIso, crc64go_iso, CRCu64, U8;
+    CRC64Jones, crc64jones, CRCu64, U8;
+    CRC64Redis, crc64redis, CRCu64, U8;
+    CRC64Ecma182, crc64ecma182, CRCu64, U8;
+    CRC64Ms, crc64ms, CRCu64, U8;
+    CRC64Nvme, crc64nvme, CRCu64, U8;
+    CRC64Xz, crc64xz, CRCu64, U8;
+    CRC82Darc, crc82darc, CRCu128, U11;
+}
+
+/// A wrapper of a CRC instance of a built-in algorithm, which implements the traits of the `digest` crate. The output is the big-endian bytes of the CRC value, like `get_crc_vec_be`.
+pub struct CRCDigest<A: CRCAlgorithm> {
+    crc: A::Crc,
+    _algorithm: PhantomData<A>,
+}
+
+impl<A: CRCAlgorithm> CRCDigest<A> {
+    /// Create a `CRCDigest` instance.
+    #[inline]
+    pub fn new() -> CRCDigest<A> {
+        CRCDigest {
+            crc: A::create(),
+            _algorithm: PhantomData,
+        }
+    }
+
+    /// Get the inner CRC instance.
+    #[inline]
+    pub fn crc(&self) -> &A::Crc {
+        &self.crc
+    }
+}
+
+impl<A: CRCAlgorithm> Default for CRCDigest<A> {
+    #[inline]
+    fn default() -> CRCDigest<A> {
+        CRCDigest::new()
+    }
+}
+
+impl<A: CRCAlgorithm> Clone for CRCDigest<A> {
+    #[inline]
+    fn clone(&self) -> CRCDigest<A> {
+        CRCDigest {
+            crc: self.crc.clone(),
+            _algorithm: PhantomData,
+        }
+    }
+}
+
+impl<A: CRCAlgorithm> Debug for CRCDigest<A> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("CRCDigest").finish_non_exhaustive()
+    }
+}
+
+impl<A: CRCAlgorithm> HashMarker for CRCDigest<A> {}
+
+impl<A: CRCAlgorithm> OutputSizeUser for CRCDigest<A> {
+    type OutputSize = A::OutputSize;
+}
+
+impl<A: CRCAlgorithm> Update for CRCDigest<A> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        A::digest(&mut self.crc, data);
+    }
+}
+
+impl<A: CRCAlgorithm> FixedOutput for CRCDigest<A> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        A::write_crc(&self.crc, out);
+    }
+}
+
+impl<A: CRCAlgorithm> Reset for CRCDigest<A> {
+    #[inline]
+    fn reset(&mut self) {
+        A::reset(&mut self.crc);
+    }
+}
+
+impl<A: CRCAlgorithm> FixedOutputReset for CRCDigest<A> {
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.clone().finalize_into(out);
+
+        A::reset(&mut self.crc);
+    }
+}
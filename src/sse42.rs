@@ -0,0 +1,48 @@
+//! CRC-32C (Castagnoli) computed by the `crc32` instruction of SSE4.2.
+
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{_mm_crc32_u64, _mm_crc32_u8};
+
+/// The reflected polynomial of CRC-32C, which is the only one the `crc32` instruction supports.
+pub(crate) const CRC32C_POLY_REV: u32 = 0x82F63B78;
+
+/// Check whether the `crc32` instruction can be used on the current CPU.
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+#[inline]
+pub(crate) fn is_available() -> bool {
+    std::is_x86_feature_detected!("sse4.2")
+}
+
+/// Check whether the `crc32` instruction can be used on the current CPU.
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+#[inline]
+pub(crate) fn is_available() -> bool {
+    cfg!(target_feature = "sse4.2")
+}
+
+/// Update a reflected CRC-32C register (without the initial value and the final xor value being applied) with some data.
+///
+/// The caller must make sure that SSE4.2 is available.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.2")]
+pub(crate) unsafe fn digest(sum: u32, data: &[u8]) -> u32 {
+    let mut sum = sum as u64;
+
+    let mut chunks = data.chunks_exact(8);
+
+    for chunk in &mut chunks {
+        let mut block = [0u8; 8];
+
+        block.copy_from_slice(chunk);
+
+        sum = _mm_crc32_u64(sum, u64::from_le_bytes(block));
+    }
+
+    let mut sum = sum as u32;
+
+    for &n in chunks.remainder() {
+        sum = _mm_crc32_u8(sum, n);
+    }
+
+    sum
+}
@@ -0,0 +1,96 @@
+//! Lookup tables which are referenced instead of being copied into every CRC instance.
+
+#[cfg(feature = "default")]
+use alloc::sync::Arc;
+
+#[cfg(feature = "default")]
+use core::ptr::NonNull;
+
+use core::ops::Deref;
+
+/// A lookup table which is either a `static` one (for built-in CRCs) or generated at runtime. A runtime-generated table is shared by the clones of a CRC instance if the `alloc` crate is available.
+///
+/// A shared table is kept as the raw pointer of an `Arc` so that it can be read in `const fn`s.
+pub(crate) enum Table<T: 'static> {
+    Static(&'static T),
+    #[cfg(feature = "default")]
+    Shared(NonNull<T>),
+    #[cfg(not(feature = "default"))]
+    Owned(T),
+}
+
+// A shared table is an `Arc<T>`.
+#[cfg(feature = "default")]
+unsafe impl<T: Send + Sync> Send for Table<T> {}
+
+#[cfg(feature = "default")]
+unsafe impl<T: Send + Sync> Sync for Table<T> {}
+
+impl<T> Table<T> {
+    /// Wrap a table generated at runtime.
+    #[inline]
+    pub(crate) fn new(table: T) -> Table<T> {
+        #[cfg(feature = "default")]
+        {
+            Table::Shared(unsafe { NonNull::new_unchecked(Arc::into_raw(Arc::new(table)) as *mut T) })
+        }
+
+        #[cfg(not(feature = "default"))]
+        {
+            Table::Owned(table)
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn get(&self) -> &T {
+        match self {
+            Table::Static(table) => table,
+            #[cfg(feature = "default")]
+            Table::Shared(table) => unsafe { table.as_ref() },
+            #[cfg(not(feature = "default"))]
+            Table::Owned(table) => table,
+        }
+    }
+}
+
+impl<T: Clone> Clone for Table<T> {
+    #[inline]
+    fn clone(&self) -> Table<T> {
+        match self {
+            Table::Static(table) => Table::Static(table),
+            #[cfg(feature = "default")]
+            Table::Shared(table) => {
+                unsafe {
+                    Arc::increment_strong_count(table.as_ptr());
+                }
+
+                Table::Shared(*table)
+            }
+            #[cfg(not(feature = "default"))]
+            Table::Owned(table) => Table::Owned(table.clone()),
+        }
+    }
+}
+
+impl<T> Drop for Table<T> {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(feature = "default")]
+        {
+            if let Table::Shared(table) = self {
+                unsafe {
+                    drop(Arc::from_raw(table.as_ptr()));
+                }
+            }
+        }
+    }
+}
+
+impl<T> Deref for Table<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "cli")]
+
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+fn crc_any(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_crc-any"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // the process may exit without reading the standard input, e.g. because of invalid arguments
+    match child.stdin.take().unwrap().write_all(stdin) {
+        Err(ref err) if err.kind() == ErrorKind::BrokenPipe => (),
+        result => result.unwrap(),
+    }
+
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn built_in() {
+    let output = crc_any(&[], b"123456789");
+
+    assert!(output.status.success());
+    assert_eq!(&b"0xCBF43926  -\n"[..], &output.stdout[..]);
+
+    let output = crc_any(&["-a", "crc32c"], b"123456789");
+
+    assert_eq!(&b"0xE3069283  -\n"[..], &output.stdout[..]);
+
+    let output = crc_any(&["--algorithm=crc-16/modbus", "--format", "dec"], b"123456789");
+
+    assert_eq!(&b"19255  -\n"[..], &output.stdout[..]);
+}
+
+#[test]
+fn custom() {
+    let output = crc_any(&["--width", "32", "--poly", "0x1EDC6F41", "--init", "FFFFFFFF", "--xorout", "0xFFFFFFFF", "--reflect", "-f", "be"], b"123456789");
+
+    assert!(output.status.success());
+    assert_eq!(&[0xE3, 0x06, 0x92, 0x83][..], &output.stdout[..]);
+
+    let output = crc_any(&["--width", "32", "--poly", "0x1EDC6F41", "--init", "FFFFFFFF", "--xorout", "0xFFFFFFFF", "--reflect", "-f", "le"], b"123456789");
+
+    assert_eq!(&[0x83, 0x92, 0x06, 0xE3][..], &output.stdout[..]);
+
+    let output = crc_any(&["--width", "32", "--poly", "0x1EDC6F41", "--init", "FFFFFFFF", "--xorout", "0xFFFFFFFF", "--reflect", "-f", "base64"], b"123456789");
+
+    assert_eq!(&b"4waSgw==  -\n"[..], &output.stdout[..]);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(Some(2), crc_any(&["-a", "CRC-32/UNKNOWN"], b"").status.code());
+    assert_eq!(Some(2), crc_any(&["--width", "16", "--poly", "0x1020"], b"").status.code());
+    assert_eq!(Some(2), crc_any(&["--width", "16"], b"").status.code());
+
+    let output = crc_any(&["--width", "8", "--poly", "0x1234507"], b"hello");
+
+    assert_eq!(Some(2), output.status.code());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("the expression is wider than the length of bits"));
+
+    assert_eq!(Some(2), crc_any(&["--width", "16", "--poly", "0x8005", "--init", "0x1FFFF"], b"").status.code());
+    assert_eq!(Some(2), crc_any(&["--width", "16", "--poly", "0x8005", "--xorout", "0x10000"], b"").status.code());
+
+    let output = crc_any(&["/nonexistent/file", "-"], b"123456789");
+
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!(&b"0xCBF43926  -\n"[..], &output.stdout[..]);
+}
+
+#[test]
+fn manifest() {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("crc-any-manifest");
+
+    let _ = fs::remove_dir_all(&directory);
+
+    fs::create_dir_all(directory.join("sub")).unwrap();
+    fs::write(directory.join("a.txt"), b"123456789").unwrap();
+    fs::write(directory.join("sub").join("b.txt"), b"https://magiclen.org").unwrap();
+
+    let directory_str = directory.to_str().unwrap();
+
+    let output = crc_any(&["--manifest", "-a", "crc-32c", directory_str], b"");
+
+    assert!(output.status.success());
+
+    let manifest = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = manifest.lines().collect();
+
+    assert_eq!(2, lines.len());
+    assert_eq!(format!("CRC-32/ISCSI 0xE3069283 {}", directory.join("a.txt").display()), lines[0]);
+    assert!(lines[1].ends_with("b.txt"));
+
+    let manifest_path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("crc-any-manifest.txt");
+
+    fs::write(&manifest_path, &manifest).unwrap();
+
+    let output = crc_any(&["--check", manifest_path.to_str().unwrap()], b"");
+
+    assert!(output.status.success());
+    assert_eq!(2, String::from_utf8(output.stdout).unwrap().matches(": OK").count());
+
+    // the manifest can also be read from the standard input
+    assert!(crc_any(&["-c"], manifest.as_bytes()).status.success());
+
+    fs::write(directory.join("a.txt"), b"987654321").unwrap();
+    fs::remove_file(directory.join("sub").join("b.txt")).unwrap();
+
+    let output = crc_any(&["--check", manifest_path.to_str().unwrap()], b"");
+
+    assert_eq!(Some(1), output.status.code());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("a.txt: FAILED\n"));
+    assert!(stdout.contains("b.txt: FAILED open or read\n"));
+}
+
+#[cfg(unix)]
+#[test]
+fn manifest_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("crc-any-manifest-symlinks");
+
+    let _ = fs::remove_dir_all(&directory);
+
+    fs::create_dir_all(directory.join("sub")).unwrap();
+    fs::write(directory.join("a.txt"), b"123456789").unwrap();
+    symlink(&directory, directory.join("sub").join("loop")).unwrap();
+    symlink("/", directory.join("sub").join("root")).unwrap();
+    symlink(directory.join("a.txt"), directory.join("sub").join("link.txt")).unwrap();
+
+    let output = crc_any(&["--manifest", "-a", "crc-32c", directory.to_str().unwrap()], b"");
+
+    assert!(output.status.success());
+
+    let manifest = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = manifest.lines().collect();
+
+    assert_eq!(2, lines.len());
+    assert!(lines[0].ends_with("a.txt"));
+    assert_eq!(format!("CRC-32/ISCSI 0xE3069283 {}", directory.join("sub").join("link.txt").display()), lines[1]);
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(stderr.contains("loop: skipping a symbolic link to a directory"));
+    assert!(stderr.contains("root: skipping a symbolic link to a directory"));
+
+    assert!(crc_any(&["-c"], manifest.as_bytes()).status.success());
+
+    // a path which cannot be written in a line is an error, but the other files are still listed
+    fs::write(directory.join("line\nbreak.txt"), b"").unwrap();
+
+    let output = crc_any(&["--manifest", "-a", "crc-32c", directory.to_str().unwrap()], b"");
+
+    assert_eq!(Some(1), output.status.code());
+    assert_eq!(2, String::from_utf8(output.stdout).unwrap().lines().count());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("the path contains a line break"));
+}
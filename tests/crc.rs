@@ -1,6 +1,17 @@
 extern crate crc_any;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "digest")]
+extern crate digest;
+#[cfg(feature = "futures-io")]
+extern crate futures;
+#[cfg(feature = "tokio")]
+extern crate tokio;
 
-use crc_any::CRC;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+
+use crc_any::{CRC, CRCBuildHasher, CRCCorrector, CRCError, Correction, CorrectionError, CRCMatch, Endian, CRCParameters, CRCParametersBuilder, CRCu8, CRCu16, CRCu32, CRCu64, CRCu128};
 
 // TODO: CRC-3
 
@@ -272,6 +283,15 @@ fn crc12() {
     assert_eq!("0x511", &crc.to_string());
 }
 
+#[test]
+fn crc12umts() {
+    let mut crc = CRC::crc12umts();
+
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!("0x88A", &crc.to_string());
+}
+
 #[test]
 fn crc12cdma2000() {
     let mut crc = CRC::crc12cdma2000();
@@ -778,4 +798,1319 @@ fn crc64jones() {
     crc.digest(b"https://magiclen.org");
 
     assert_eq!("0x4BE96FCDBAD0D303", &crc.to_string());
-}
\ No newline at end of file
+}
+
+// TODO: CRC-82
+
+#[test]
+fn crc82darc() {
+    let mut crc = CRC::crc82darc();
+
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!("0x212405CB5E5829123EB66", &crc.to_string());
+}
+
+#[test]
+fn crc_u128() {
+    let data = slicing_data();
+
+    for &(poly, bits, reflect) in [(0x0308C0111011401440411u128, 82, true), (0x0308C0111011401440411, 82, false), (0x1D, 65, false), (0x0000000000000000000000000000001Bu128.reverse_bits(), 128, true), (0x1DB710641DB710641DB710641DB71064, 128, false)].iter() {
+        let mut crc = CRCu128::create_crc(poly, bits, 0x0123456789ABCDEF0123456789ABCDEF & (u128::MAX >> (128 - bits)), 0, reflect);
+        let mut bitwise = crc.clone();
+
+        crc.digest(&data);
+
+        if reflect {
+            bitwise.digest_bits_lsb(&data[..1], 7);
+
+            for i in 7..data.len() * 8 {
+                bitwise.digest_symbol_lsb((data[i / 8] >> (i % 8)) as u64 & 1, 1);
+            }
+        } else {
+            bitwise.digest_bits(&data[..1], 7);
+
+            for i in 7..data.len() * 8 {
+                bitwise.digest_symbol((data[i / 8] >> (7 - i % 8)) as u64 & 1, 1);
+            }
+        }
+
+        assert_eq!(crc.get_crc(), bitwise.get_crc(), "bits: {}, reflect: {}", bits, reflect);
+
+        let (a, b) = data.split_at(300);
+
+        let mut crc_a = crc.clone();
+        let mut crc_b = crc.clone();
+
+        crc_a.reset();
+        crc_a.digest(a);
+        crc_b.reset();
+        crc_b.digest(b);
+
+        assert_eq!(crc.get_crc(), crc.combine(crc_a.get_crc(), crc_b.get_crc(), b.len() as u64));
+    }
+}
+
+// TODO: Slicing
+
+fn slicing_data() -> Vec<u8> {
+    (0..1031u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect()
+}
+
+fn assert_slicing(mut builtin: CRC, mut custom: CRC) {
+    let data = slicing_data();
+
+    for &len in [0, 1, 7, 8, 15, 16, 17, 31, 32, 100, 383, 384, 385, 400, 1024, 1031].iter() {
+        builtin.reset();
+        custom.reset();
+
+        builtin.digest(&data[..len / 3]);
+        builtin.digest(&data[len / 3..len]);
+
+        for n in data[..len].iter() {
+            custom.digest(&[*n]);
+        }
+
+        assert_eq!(custom.get_crc(), builtin.get_crc(), "length: {}", len);
+    }
+}
+
+#[test]
+fn crc24_slicing() {
+    assert_slicing(CRC::crc24(), CRC::create_crc(0x00864CFB, 24, 0x00B704CE, 0x00000000, false));
+    assert_slicing(CRC::crc24ble(), CRC::create_crc(0x00DA6000, 24, 0x00555555, 0x00000000, true));
+    assert_slicing(CRC::crc24interlaken(), CRC::create_crc(0x00328B63, 24, 0x00FFFFFF, 0x00FFFFFF, false));
+}
+
+#[test]
+fn crc32_slicing() {
+    assert_slicing(CRC::crc32(), CRC::create_crc(0xEDB88320, 32, 0xFFFFFFFF, 0xFFFFFFFF, true));
+    assert_slicing(CRC::crc32c(), CRC::create_crc(0x82F63B78, 32, 0xFFFFFFFF, 0xFFFFFFFF, true));
+    assert_slicing(CRC::crc32mpeg2(), CRC::create_crc(0x04C11DB7, 32, 0xFFFFFFFF, 0x00000000, false));
+    assert_slicing(CRC::crc32autosar(), CRC::create_crc(0xC8DF352F, 32, 0xFFFFFFFF, 0xFFFFFFFF, true));
+}
+
+#[test]
+fn crc40_slicing() {
+    assert_slicing(CRC::crc40gsm(), CRC::create_crc(0x0000000004820009, 40, 0x0000000000000000, 0x000000FFFFFFFFFF, false));
+}
+
+#[test]
+fn crc64_slicing() {
+    assert_slicing(CRC::crc64(), CRC::create_crc(0x42F0E1EBA9EA3693, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, false));
+    assert_slicing(CRC::crc64iso(), CRC::create_crc(0xD800000000000000, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true));
+    assert_slicing(CRC::crc64xz(), CRC::create_crc(0xC96C5795D7870F42, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true));
+    assert_slicing(CRC::crc64ecma182(), CRC::create_crc(0x42F0E1EBA9EA3693, 64, 0x0000000000000000, 0x0000000000000000, false));
+}
+
+#[test]
+fn crc32c_hardware() {
+    let data = slicing_data();
+
+    let mut crc = CRC::crc32c();
+
+    crc.digest(b"123456789");
+
+    assert_eq!(0xE3069283, crc.get_crc());
+
+    let mut crc_bytewise = CRC::create_crc(0x82F63B78, 32, 0xFFFFFFFF, 0xFFFFFFFF, true);
+
+    crc.reset();
+
+    crc.digest(&data);
+
+    for n in data.iter() {
+        crc_bytewise.digest(&[*n]);
+    }
+
+    assert_eq!(crc_bytewise.get_crc(), crc.get_crc());
+}
+
+#[test]
+fn crc32c_hardware_long() {
+    let data: Vec<u8> = slicing_data().iter().cycle().take(64 * 1024 + 13).cloned().collect();
+
+    let mut crc = CRCu32::crc32c();
+
+    crc.digest(&data[..5]);
+    crc.digest(&data[5..]);
+
+    assert_eq!(bitwise_crc(0x82F63B78, 32, 0xFFFFFFFF, 0xFFFFFFFF, true, true, &data) as u32, crc.get_crc());
+}
+
+#[test]
+fn custom_folding() {
+    assert_slicing(CRC::crc32q(), CRC::create_crc(0x814141AB, 32, 0x00000000, 0x00000000, false));
+    assert_slicing(CRC::crc24os9(), CRC::create_crc(0x00800063, 24, 0x00FFFFFF, 0x00FFFFFF, false));
+    assert_slicing(CRC::crc64jones(), CRC::create_crc(0x95AC9329AC4BC9B5, 64, 0x0000000000000000, 0x0000000000000000, true));
+    assert_slicing(CRC::create_crc(0x9A6C9329AC4BC9B5, 64, 0x0123456789ABCDEF, 0x0000000000000000, false), CRC::create_crc(0x9A6C9329AC4BC9B5, 64, 0x0123456789ABCDEF, 0x0000000000000000, false));
+    assert_slicing(CRC::create_crc(0x00A00805, 48, 0x00000000, 0xFFFFFFFF, true), CRC::create_crc(0x00A00805, 48, 0x00000000, 0xFFFFFFFF, true));
+    assert_slicing(CRC::create_crc(0x1021, 16, 0xFFFF, 0x0000, false), CRC::create_crc(0x1021, 16, 0xFFFF, 0x0000, false));
+}
+
+/// A bit-by-bit reference implementation over a stream of bits. `poly` is reflected if `reflect_in` is `true`, as `create_crc_with_reflection` accepts, and the register is reflected at the end if `reflect_out` is `true`.
+fn bitwise_crc_bits(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect_in: bool, reflect_out: bool, stream: &[bool]) -> u64 {
+    let high_bit = 1u64 << (bits - 1);
+    let mask = ((high_bit - 1) << 1) | 1;
+    let reverse = |n: u64| n.reverse_bits() >> (64 - bits);
+
+    let poly = if reflect_in { reverse(poly) } else { poly };
+
+    let mut sum = initial;
+
+    for &bit in stream {
+        let carry = (sum & high_bit != 0) != bit;
+
+        sum = (sum << 1) & mask;
+
+        if carry {
+            sum ^= poly;
+        }
+    }
+
+    if reflect_out {
+        sum = reverse(sum);
+    }
+
+    (sum ^ final_xor) & mask
+}
+
+/// Expand bytes into a stream of bits, taking the bits of each byte from the least significant one if `reflect` is `true`, or from the most significant one otherwise.
+fn bits_of(data: &[u8], reflect: bool) -> Vec<bool> {
+    data.iter()
+        .flat_map(|&n| {
+            (0..8).map(move |i| {
+                if reflect {
+                    (n >> i) & 1 == 1
+                } else {
+                    (n >> (7 - i)) & 1 == 1
+                }
+            })
+        })
+        .collect()
+}
+
+/// A bit-by-bit reference implementation. `poly` is reflected if `reflect_in` is `true`, as `create_crc_with_reflection` accepts.
+fn bitwise_crc(poly: u64, bits: u8, initial: u64, final_xor: u64, reflect_in: bool, reflect_out: bool, data: &[u8]) -> u64 {
+    bitwise_crc_bits(poly, bits, initial, final_xor, reflect_in, reflect_out, &bits_of(data, reflect_in))
+}
+
+#[test]
+fn non_byte_aligned_widths() {
+    let data = slicing_data();
+
+    let mut crc5usb = CRCu8::crc5usb();
+    let mut crc5usb_u16 = CRCu16::create_crc(0x14, 5, 0x1F, 0x1F, true);
+    let mut crc5epc_u32 = CRCu32::create_crc(0x09, 5, 0x09, 0x00, false);
+    let mut crc12_u64 = CRCu64::create_crc(0x080F, 12, 0x0000, 0x0000, false);
+
+    crc5usb.digest(b"123456789");
+    crc5usb_u16.digest(b"123456789");
+    crc5epc_u32.digest(b"123456789");
+    crc12_u64.digest(b"123456789");
+
+    assert_eq!(0x19, crc5usb.get_crc());
+    assert_eq!(0x19, crc5usb_u16.get_crc());
+    assert_eq!(0x00, crc5epc_u32.get_crc());
+    assert_eq!(0x0F5B, crc12_u64.get_crc());
+
+    for &(poly, bits, initial, final_xor, reflect) in [
+        (0x03, 3, 0x00, 0x07, false),
+        (0x0C, 4, 0x00, 0x00, true),
+        (0x14, 5, 0x1F, 0x1F, true),
+        (0x2F, 6, 0x00, 0x3F, false),
+        (0x0385, 11, 0x001A, 0x0000, false),
+        (0x2804, 14, 0x0000, 0x0000, true),
+        (0x4599, 15, 0x0000, 0x0000, false),
+        (0x0001685B, 17, 0x00000000, 0x00000000, false),
+        (0x2030B9C7, 30, 0x3FFFFFFF, 0x3FFFFFFF, false),
+        (0x0000000004C11DB7, 33, 0x0000000000000000, 0x00000001FFFFFFFF, false),
+        (0x0000748A5E4A0A4D, 47, 0x00001234ABCD0000, 0x0000000000000000, true),
+    ].iter() {
+        let mut crc = CRC::create_crc(poly, bits, initial, final_xor, reflect);
+
+        for &len in [0, 1, 9, 200, 1031].iter() {
+            crc.reset();
+            crc.digest(&data[..len]);
+
+            assert_eq!(bitwise_crc(poly, bits, initial, final_xor, reflect, reflect, &data[..len]), crc.get_crc(), "bits: {}, length: {}", bits, len);
+        }
+    }
+}
+
+// TODO: Sharing
+
+#[test]
+fn small_instances() {
+    assert!(core::mem::size_of::<CRC>() <= 256);
+    assert!(core::mem::size_of::<CRCu64>() <= 256);
+}
+
+#[test]
+fn clone_shares_table() {
+    let mut crc = CRC::create_crc(0x9A6C9329AC4BC9B5, 64, 0x0123456789ABCDEF, 0x0000000000000000, true);
+
+    crc.digest(b"1234");
+
+    let mut cloned = crc.clone();
+
+    crc.digest(b"56789");
+
+    assert_ne!(crc.get_crc(), cloned.get_crc());
+
+    cloned.digest(b"56789");
+
+    assert_eq!(crc.get_crc(), cloned.get_crc());
+
+    let mut crc64 = CRCu64::crc64();
+    let mut cloned = crc64.clone();
+
+    crc64.digest(b"123456789");
+    cloned.digest(b"123456789");
+
+    assert_eq!(0x62EC59E3F1A4F00A, cloned.get_crc());
+    assert_eq!(crc64.get_crc(), cloned.get_crc());
+}
+
+// TODO: Const
+
+static HEADER: CRCu32 = {
+    let mut crc = CRCu32::crc32();
+
+    crc.digest_slice(b"123456789");
+
+    crc
+};
+
+const HEADER_CRC: u32 = HEADER.get_crc();
+
+static CRC24_TABLE: [u32; 256] = CRCu32::crc_table(0x864CFB, 24);
+static CRC24: CRCu32 = CRCu32::create_crc_with_lookup_table(&CRC24_TABLE, 24, 0xB704CE, 0x000000, false);
+
+static CRC5USB_TABLE: [u8; 256] = CRCu8::crc_reflect_table(0x14);
+static CRC5USB: CRCu8 = CRCu8::create_crc_with_lookup_table(&CRC5USB_TABLE, 5, 0x1F, 0x1F, true);
+
+static CRC64: CRCu64 = {
+    let mut crc = CRCu64::crc64iso();
+
+    crc.digest_slice(b"123456789");
+
+    crc
+};
+
+#[test]
+fn const_crc() {
+    assert_eq!(0xCBF43926, HEADER_CRC);
+    assert_eq!(0xB90956C775A41001, CRC64.get_crc());
+
+    let mut crc = CRC24.clone();
+
+    crc.digest(b"123456789");
+
+    assert_eq!(0x21CF02, crc.get_crc());
+
+    let mut crc = CRC5USB.clone();
+
+    crc.digest(b"123456789");
+
+    assert_eq!(0x19, crc.get_crc());
+
+    let mut crc = CRCu16::crc16();
+
+    crc.digest_slice(b"123456789");
+
+    assert_eq!(0xBB3D, crc.get_crc());
+}
+
+// TODO: Combine
+
+fn assert_combine(mut crc: CRC) {
+    let data = slicing_data();
+
+    crc.reset();
+    crc.digest(&data);
+
+    let expected = crc.get_crc();
+
+    for &split in [0, 1, 3, 8, 100, 511, 1030, 1031].iter() {
+        let (a, b) = data.split_at(split);
+
+        crc.reset();
+        crc.digest(a);
+
+        let crc_a = crc.get_crc();
+
+        crc.reset();
+        crc.digest(b);
+
+        let crc_b = crc.get_crc();
+
+        assert_eq!(expected, crc.combine(crc_a, crc_b, b.len() as u64), "split: {}", split);
+    }
+}
+
+#[test]
+fn combine_u128() {
+    let data = slicing_data();
+    let (a, b) = data.split_at(100);
+
+    for mut crc in [CRC::crc16modbus(), CRC::crc64(), CRC::crc82darc()] {
+        crc.digest(&data);
+
+        let expected = crc.get_crc_u128();
+
+        assert_eq!(expected as u64, crc.get_crc());
+
+        crc.reset();
+        crc.digest(a);
+
+        let crc_a = crc.get_crc_u128();
+
+        crc.reset();
+        crc.digest(b);
+
+        let crc_b = crc.get_crc_u128();
+
+        assert_eq!(expected, crc.combine_u128(crc_a, crc_b, b.len() as u64));
+    }
+
+    let mut crc = CRC::crc82darc();
+
+    crc.digest(b"123456789");
+
+    assert_eq!(CRCParameters::CRC_82_DARC.check, crc.get_crc_u128());
+    assert!(crc.get_crc_u128() >> 64 != 0);
+}
+
+#[test]
+fn combine() {
+    assert_combine(CRC::crc3gsm());
+    assert_combine(CRC::crc5usb());
+    assert_combine(CRC::crc8());
+    assert_combine(CRC::crc8cdma2000());
+    assert_combine(CRC::crc12());
+    assert_combine(CRC::crc12umts());
+    assert_combine(CRC::create_crc_with_reflection(0x8408, 16, 0x1D0F, 0x0000, true, false));
+    assert_combine(CRC::crc16riello());
+    assert_combine(CRC::crc16teledisk());
+    assert_combine(CRC::crc24ble());
+    assert_combine(CRC::crc24flexray_a());
+    assert_combine(CRC::crc32());
+    assert_combine(CRC::crc32c());
+    assert_combine(CRC::crc32mhash());
+    assert_combine(CRC::crc40gsm());
+    assert_combine(CRC::crc64());
+    assert_combine(CRC::crc64iso());
+    assert_combine(CRC::create_crc(0x1, 1, 0x1, 0x0, false));
+    assert_combine(CRC::create_crc(0x0000748A5E4A0A4D, 47, 0x00001234ABCD0000, 0x00000000DEADBEEF, true));
+
+    let crc32 = CRCu32::crc32();
+
+    // CRC-32 of "1234" and "56789"
+    assert_eq!(0xCBF43926, crc32.combine(0x9BE3E0A3, 0x131DA070, 5));
+}
+
+// TODO: Parallel
+
+#[cfg(feature = "rayon")]
+#[test]
+fn digest_parallel() {
+    let data = slicing_data().into_iter().cycle().take(5 * 1024 * 1024 + 13).collect::<Vec<u8>>();
+
+    // split the data into more than one chunk even on a single-core machine
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    pool.install(|| {
+        for crc in [CRC::crc5usb(), CRC::crc12umts(), CRC::crc16riello(), CRC::crc24(), CRC::crc32(), CRC::crc32c(), CRC::crc32mhash(), CRC::crc40gsm(), CRC::crc64iso()].iter() {
+            let mut sequential = crc.clone();
+            let mut parallel = crc.clone();
+
+            sequential.digest(b"head");
+            sequential.digest(&data);
+
+            parallel.digest(b"head");
+            parallel.digest_parallel(&data);
+
+            assert_eq!(sequential.get_crc(), parallel.get_crc());
+
+            sequential.digest(b"tail");
+            parallel.digest_parallel(b"tail");
+
+            assert_eq!(sequential.get_crc(), parallel.get_crc());
+        }
+    });
+}
+
+// TODO: Bits
+
+#[test]
+fn digest_bits() {
+    let data = slicing_data();
+
+    let parameters: [(u64, u8, u64, u64, bool); 8] = [
+        (0x14, 5, 0x1F, 0x1F, true),
+        (0x4599, 15, 0x0000, 0x0000, false),
+        (0x1685B, 17, 0x00000, 0x00000, false),
+        (0x102899, 21, 0x000000, 0x000000, false),
+        (0xA001, 16, 0x1234, 0x0000, true),
+        (0xEDB88320, 32, 0xFFFFFFFF, 0xFFFFFFFF, true),
+        (0x4820009, 40, 0x0000000000, 0xFFFFFFFFFF, false),
+        (0xD800000000000000, 64, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, true),
+    ];
+
+    for &(poly, bits, initial, final_xor, reflect) in parameters.iter() {
+        for &bit_len in [0, 1, 7, 8, 11, 19, 64, 83, 1000, 8247].iter() {
+            let msb = &bits_of(&data, false)[..bit_len];
+            let lsb = &bits_of(&data, true)[..bit_len];
+
+            let mut crc = CRC::create_crc(poly, bits, initial, final_xor, reflect);
+
+            crc.digest_bits(&data, bit_len);
+
+            assert_eq!(bitwise_crc_bits(poly, bits, initial, final_xor, reflect, reflect, msb), crc.get_crc(), "bits: {}, bit_len: {}", bits, bit_len);
+
+            crc.reset();
+            crc.digest_bits_lsb(&data, bit_len);
+
+            assert_eq!(bitwise_crc_bits(poly, bits, initial, final_xor, reflect, reflect, lsb), crc.get_crc(), "bits: {}, bit_len: {}", bits, bit_len);
+        }
+
+        let mut crc = CRC::create_crc(poly, bits, initial, final_xor, reflect);
+        let mut expected = crc.clone();
+
+        expected.digest(&data);
+
+        if reflect {
+            crc.digest_bits_lsb(&data, data.len() * 8);
+        } else {
+            crc.digest_bits(&data, data.len() * 8);
+        }
+
+        assert_eq!(expected.get_crc(), crc.get_crc());
+    }
+}
+
+#[test]
+fn digest_symbol() {
+    // a SETUP token to address 0 and endpoint 0 is transmitted as 0x2D 0x00 0x10
+    let mut crc = CRCu8::crc5usb();
+
+    crc.digest_symbol_lsb(0x00, 7);
+    crc.digest_symbol_lsb(0x0, 4);
+
+    assert_eq!(0x02, crc.get_crc());
+
+    // 10-bit symbols are the same as the bits of the bytes they are packed into
+    let mut crc = CRCu16::crc15can();
+    let mut expected = crc.clone();
+
+    crc.digest_symbol(0x2A5, 10);
+    crc.digest_symbol(0x0F3, 10);
+    crc.digest_symbol(0x1, 4);
+
+    expected.digest_bits(&[0xA9, 0x4F, 0x31], 24);
+
+    assert_eq!(expected.get_crc(), crc.get_crc());
+}
+
+// TODO: Reflection
+
+#[test]
+fn independent_reflection() {
+    let mut crc = CRCu16::crc12umts();
+
+    crc.digest(b"123456789");
+
+    assert_eq!(0xDAF, crc.get_crc());
+
+    let data = slicing_data();
+
+    let parameters: [(u64, u8, u64, u64); 6] = [(0x09, 5, 0x09, 0x00), (0x80F, 12, 0x000, 0x000), (0x1021, 16, 0xFFFF, 0x0000), (0x864CFB, 24, 0xB704CE, 0x000000), (0x04C11DB7, 32, 0xFFFFFFFF, 0xFFFFFFFF), (0x42F0E1EBA9EA3693, 64, 0x0123456789ABCDEF, 0x0000000000000000)];
+
+    for &(poly, bits, initial, final_xor) in parameters.iter() {
+        for &(reflect_in, reflect_out) in [(false, false), (false, true), (true, false), (true, true)].iter() {
+            let expression = if reflect_in { poly.reverse_bits() >> (64 - bits) } else { poly };
+
+            let mut crc = CRC::create_crc_with_reflection(expression, bits, initial, final_xor, reflect_in, reflect_out);
+
+            for &length in [0, 1, 9, 200, 1031].iter() {
+                crc.reset();
+                crc.digest(&data[..length]);
+
+                assert_eq!(bitwise_crc(expression, bits, initial, final_xor, reflect_in, reflect_out, &data[..length]), crc.get_crc(), "bits: {}, reflect_in: {}, reflect_out: {}, length: {}", bits, reflect_in, reflect_out, length);
+            }
+        }
+    }
+}
+
+// TODO: Parameters
+
+fn built_in_parameters() -> Vec<(CRC, CRCParameters)> {
+    vec![
+        (CRC::crc3gsm(), CRCParameters::CRC_3_GSM),
+        (CRC::crc4itu(), CRCParameters::CRC_4_G_704),
+        (CRC::crc4interlaken(), CRCParameters::CRC_4_INTERLAKEN),
+        (CRC::crc5epc(), CRCParameters::CRC_5_EPC),
+        (CRC::crc5itu(), CRCParameters::CRC_5_G_704),
+        (CRC::crc5usb(), CRCParameters::CRC_5_USB),
+        (CRC::crc6cdma2000_a(), CRCParameters::CRC_6_CDMA2000_A),
+        (CRC::crc6cdma2000_b(), CRCParameters::CRC_6_CDMA2000_B),
+        (CRC::crc6darc(), CRCParameters::CRC_6_DARC),
+        (CRC::crc6gsm(), CRCParameters::CRC_6_GSM),
+        (CRC::crc6itu(), CRCParameters::CRC_6_G_704),
+        (CRC::crc7(), CRCParameters::CRC_7_MMC),
+        (CRC::crc7umts(), CRCParameters::CRC_7_UMTS),
+        (CRC::crc8(), CRCParameters::CRC_8_SMBUS),
+        (CRC::crc8cdma2000(), CRCParameters::CRC_8_CDMA2000),
+        (CRC::crc8darc(), CRCParameters::CRC_8_DARC),
+        (CRC::crc8dvb_s2(), CRCParameters::CRC_8_DVB_S2),
+        (CRC::crc8ebu(), CRCParameters::CRC_8_TECH_3250),
+        (CRC::crc8icode(), CRCParameters::CRC_8_I_CODE),
+        (CRC::crc8itu(), CRCParameters::CRC_8_I_432_1),
+        (CRC::crc8maxim(), CRCParameters::CRC_8_MAXIM_DOW),
+        (CRC::crc8rohc(), CRCParameters::CRC_8_ROHC),
+        (CRC::crc8wcdma(), CRCParameters::CRC_8_WCDMA),
+        (CRC::crc10(), CRCParameters::CRC_10_ATM),
+        (CRC::crc10cdma2000(), CRCParameters::CRC_10_CDMA2000),
+        (CRC::crc10gsm(), CRCParameters::CRC_10_GSM),
+        (CRC::crc11(), CRCParameters::CRC_11_FLEXRAY),
+        (CRC::crc12(), CRCParameters::CRC_12_DECT),
+        (CRC::crc12umts(), CRCParameters::CRC_12_UMTS),
+        (CRC::crc12cdma2000(), CRCParameters::CRC_12_CDMA2000),
+        (CRC::crc12gsm(), CRCParameters::CRC_12_GSM),
+        (CRC::crc13bbc(), CRCParameters::CRC_13_BBC),
+        (CRC::crc14darc(), CRCParameters::CRC_14_DARC),
+        (CRC::crc14gsm(), CRCParameters::CRC_14_GSM),
+        (CRC::crc15can(), CRCParameters::CRC_15_CAN),
+        (CRC::crc15mpt1327(), CRCParameters::CRC_15_MPT1327),
+        (CRC::crc16(), CRCParameters::CRC_16_ARC),
+        (CRC::crc16ccitt_false(), CRCParameters::CRC_16_IBM_3740),
+        (CRC::crc16aug_ccitt(), CRCParameters::CRC_16_SPI_FUJITSU),
+        (CRC::crc16buypass(), CRCParameters::CRC_16_UMTS),
+        (CRC::crc16cdma2000(), CRCParameters::CRC_16_CDMA2000),
+        (CRC::crc16dds_110(), CRCParameters::CRC_16_DDS_110),
+        (CRC::crc16dect_r(), CRCParameters::CRC_16_DECT_R),
+        (CRC::crc16dect_x(), CRCParameters::CRC_16_DECT_X),
+        (CRC::crc16dnp(), CRCParameters::CRC_16_DNP),
+        (CRC::crc16en_13757(), CRCParameters::CRC_16_EN_13757),
+        (CRC::crc16genibus(), CRCParameters::CRC_16_GENIBUS),
+        (CRC::crc16maxim(), CRCParameters::CRC_16_MAXIM_DOW),
+        (CRC::crc16mcrf4cc(), CRCParameters::CRC_16_MCRF4XX),
+        (CRC::crc16riello(), CRCParameters::CRC_16_RIELLO),
+        (CRC::crc16t10_dif(), CRCParameters::CRC_16_T10_DIF),
+        (CRC::crc16teledisk(), CRCParameters::CRC_16_TELEDISK),
+        (CRC::crc16tms13157(), CRCParameters::CRC_16_TMS37157),
+        (CRC::crc16usb(), CRCParameters::CRC_16_USB),
+        (CRC::crc_a(), CRCParameters::CRC_16_ISO_IEC_14443_3_A),
+        (CRC::crc16kermit(), CRCParameters::CRC_16_KERMIT),
+        (CRC::crc16modbus(), CRCParameters::CRC_16_MODBUS),
+        (CRC::crc16_x25(), CRCParameters::CRC_16_IBM_SDLC),
+        (CRC::crc16xmodem(), CRCParameters::CRC_16_XMODEM),
+        (CRC::crc17can(), CRCParameters::CRC_17_CAN_FD),
+        (CRC::crc21can(), CRCParameters::CRC_21_CAN_FD),
+        (CRC::crc24(), CRCParameters::CRC_24_OPENPGP),
+        (CRC::crc24ble(), CRCParameters::CRC_24_BLE),
+        (CRC::crc24flexray_a(), CRCParameters::CRC_24_FLEXRAY_A),
+        (CRC::crc24flexray_b(), CRCParameters::CRC_24_FLEXRAY_B),
+        (CRC::crc24lte_a(), CRCParameters::CRC_24_LTE_A),
+        (CRC::crc24lte_b(), CRCParameters::CRC_24_LTE_B),
+        (CRC::crc24os9(), CRCParameters::CRC_24_OS_9),
+        (CRC::crc30cdma(), CRCParameters::CRC_30_CDMA),
+        (CRC::crc32(), CRCParameters::CRC_32_ISO_HDLC),
+        (CRC::crc32bzip2(), CRCParameters::CRC_32_BZIP2),
+        (CRC::crc32c(), CRCParameters::CRC_32_ISCSI),
+        (CRC::crc32d(), CRCParameters::CRC_32_BASE91_D),
+        (CRC::crc32mpeg2(), CRCParameters::CRC_32_MPEG_2),
+        (CRC::crc32posix(), CRCParameters::CRC_32_CKSUM),
+        (CRC::crc32q(), CRCParameters::CRC_32_AIXM),
+        (CRC::crc32jamcrc(), CRCParameters::CRC_32_JAMCRC),
+        (CRC::crc32xfer(), CRCParameters::CRC_32_XFER),
+        (CRC::crc40gsm(), CRCParameters::CRC_40_GSM),
+        (CRC::crc64(), CRCParameters::CRC_64_WE),
+        (CRC::crc64iso(), CRCParameters::CRC_64_GO_ISO),
+        (CRC::crc64jones(), CRCParameters::CRC_64_REDIS),
+        (CRC::crc3rohc(), CRCParameters::CRC_3_ROHC),
+        (CRC::crc5epc_c1g2(), CRCParameters::CRC_5_EPC_C1G2),
+        (CRC::crc7rohc(), CRCParameters::CRC_7_ROHC),
+        (CRC::crc8autosar(), CRCParameters::CRC_8_AUTOSAR),
+        (CRC::crc8bluetooth(), CRCParameters::CRC_8_BLUETOOTH),
+        (CRC::crc8gsm_a(), CRCParameters::CRC_8_GSM_A),
+        (CRC::crc8gsm_b(), CRCParameters::CRC_8_GSM_B),
+        (CRC::crc8hitag(), CRCParameters::CRC_8_HITAG),
+        (CRC::crc8lte(), CRCParameters::CRC_8_LTE),
+        (CRC::crc8mifare_mad(), CRCParameters::CRC_8_MIFARE_MAD),
+        (CRC::crc8nrsc5(), CRCParameters::CRC_8_NRSC_5),
+        (CRC::crc8opensafety(), CRCParameters::CRC_8_OPENSAFETY),
+        (CRC::crc8sae_j1850(), CRCParameters::CRC_8_SAE_J1850),
+        (CRC::crc11umts(), CRCParameters::CRC_11_UMTS),
+        (CRC::crc16cms(), CRCParameters::CRC_16_CMS),
+        (CRC::crc16gsm(), CRCParameters::CRC_16_GSM),
+        (CRC::crc16lj1200(), CRCParameters::CRC_16_LJ1200),
+        (CRC::crc16m17(), CRCParameters::CRC_16_M17),
+        (CRC::crc16nrsc5(), CRCParameters::CRC_16_NRSC_5),
+        (CRC::crc16opensafety_a(), CRCParameters::CRC_16_OPENSAFETY_A),
+        (CRC::crc16opensafety_b(), CRCParameters::CRC_16_OPENSAFETY_B),
+        (CRC::crc16profibus(), CRCParameters::CRC_16_PROFIBUS),
+        (CRC::crc24interlaken(), CRCParameters::CRC_24_INTERLAKEN),
+        (CRC::crc31philips(), CRCParameters::CRC_31_PHILIPS),
+        (CRC::crc32autosar(), CRCParameters::CRC_32_AUTOSAR),
+        (CRC::crc32cd_rom_edc(), CRCParameters::CRC_32_CD_ROM_EDC),
+        (CRC::crc32mef(), CRCParameters::CRC_32_MEF),
+        (CRC::crc64ecma182(), CRCParameters::CRC_64_ECMA_182),
+        (CRC::crc64ms(), CRCParameters::CRC_64_MS),
+        (CRC::crc64nvme(), CRCParameters::CRC_64_NVME),
+        (CRC::crc64xz(), CRCParameters::CRC_64_XZ),
+        (CRC::crc16ibm_sdlc(), CRCParameters::CRC_16_IBM_SDLC),
+        (CRC::crc32iscsi(), CRCParameters::CRC_32_ISCSI),
+        (CRC::crc64go_iso(), CRCParameters::CRC_64_GO_ISO),
+        (CRC::crc64redis(), CRCParameters::CRC_64_REDIS),
+        (CRC::crc64we(), CRCParameters::CRC_64_WE),
+        (CRC::crc82darc(), CRCParameters::CRC_82_DARC),
+    ]
+}
+
+#[test]
+fn parameters() {
+    for (mut crc, parameters) in built_in_parameters() {
+        let rebuilt = parameters.to_builder().name(parameters.name).build();
+
+        assert_eq!(parameters, rebuilt);
+
+        assert_eq!(CRCParameters { name: "", ..parameters }, crc.parameters(), "{}", parameters.name);
+
+        crc.digest(b"123456789");
+
+        assert_eq!(parameters.check, crc.get_crc_u128(), "{}", parameters.name);
+
+        let mut crc = CRC::create_crc_with_parameters(&parameters);
+
+        crc.digest(b"123456789");
+
+        assert_eq!(parameters.check, crc.get_crc_u128(), "{}", parameters.name);
+
+        // an error-free codeword (its CRC value is appended in the order which the output is shifted out)
+        let mut crc = CRC::create_crc_with_parameters(&CRCParametersBuilder::new(parameters.width, parameters.poly).init(parameters.init).refin(parameters.refin).refout(parameters.refout).build());
+
+        crc.digest(b"123456789");
+
+        let value = crc.get_crc_u128() ^ parameters.xorout;
+
+        let high_bits = parameters.width.saturating_sub(64);
+
+        if parameters.refout {
+            crc.digest_symbol_lsb(value as u64, parameters.width - high_bits);
+            crc.digest_symbol_lsb((value >> 64) as u64, high_bits);
+        } else {
+            crc.digest_symbol((value >> 64) as u64, high_bits);
+            crc.digest_symbol(value as u64, parameters.width - high_bits);
+        }
+
+        assert_eq!(parameters.residue, crc.get_crc_u128(), "{}", parameters.name);
+    }
+}
+
+#[test]
+fn parameters_builder() {
+    const SEEDED: CRCParameters = CRCParameters::CRC_32_ISCSI.to_builder().init(0x12345678).build();
+
+    let mut crc = CRCu32::create_crc_with_parameters(&SEEDED);
+
+    assert_eq!(0x1EDC6F41, crc.poly());
+    assert_eq!(0x12345678, crc.init());
+    assert!(crc.refin() && crc.refout());
+    assert_eq!(0xFFFFFFFF, crc.xorout());
+    assert_eq!("", SEEDED.name);
+
+    crc.digest(b"123456789");
+
+    assert_eq!(SEEDED.check, crc.get_crc() as u128);
+    assert_eq!(bitwise_crc(0x82F63B78, 32, 0x12345678, 0xFFFFFFFF, true, true, b"123456789") as u128, SEEDED.check);
+    assert_eq!(CRCParameters::CRC_32_ISCSI.residue, SEEDED.residue);
+}
+
+// TODO: Names
+
+#[test]
+fn from_name() {
+    for name in ["CRC-32/ISO-HDLC", "crc-32/iso-hdlc", "CRC-32", "CRC-32/ADCCP", "PKZIP", "pkzip", "crc32"].iter() {
+        let mut crc = CRC::from_name(name).unwrap();
+
+        crc.digest(b"123456789");
+
+        assert_eq!(0xCBF43926, crc.get_crc(), "{}", name);
+    }
+
+    let mut crc = CRC::from_name("CRC-16/ARC").unwrap();
+    crc.digest(b"123456789");
+    assert_eq!(0xBB3D, crc.get_crc());
+
+    let mut crc = CRC::from_name("x-25").unwrap();
+    crc.digest(b"123456789");
+    assert_eq!(0x906E, crc.get_crc());
+
+    // a function which is not in the CRC RevEng catalogue
+    let mut crc = CRC::from_name("crc32mhash").unwrap();
+    crc.digest(b"123456789");
+    let mut expected = CRCu32::crc32mhash();
+    expected.digest(b"123456789");
+    assert_eq!(expected.get_crc() as u64, crc.get_crc());
+
+    // CRC-5/EPC is an alias of CRC-5/EPC-C1G2, rather than `crc5epc`
+    assert_eq!(&CRCParameters::CRC_5_EPC_C1G2, CRCParameters::from_name("CRC-5/EPC").unwrap());
+    assert_eq!(CRCParameters::CRC_5_EPC, CRC::from_name("crc5epc").unwrap().parameters().to_builder().name("crc5epc").build());
+
+    assert!(CRC::from_name("CRC-32/UNKNOWN").is_none());
+    assert!(CRCParameters::from_name("crc32").is_none());
+
+    assert_eq!(vec!["CRC-32", "CRC-32/ADCCP", "CRC-32/V-42", "CRC-32/XZ", "PKZIP"], CRCParameters::CRC_32_ISO_HDLC.aliases().collect::<Vec<&str>>());
+}
+
+#[test]
+fn catalogue() {
+    let catalogue: Vec<&CRCParameters> = CRCParameters::catalogue().collect();
+
+    assert_eq!(113, catalogue.len());
+
+    for (i, parameters) in catalogue.iter().enumerate() {
+        assert!(catalogue[..i].iter().all(|p| p.name != parameters.name), "{}", parameters.name);
+
+        assert_eq!(*parameters, CRCParameters::from_name(parameters.name).unwrap());
+        assert_eq!(*parameters, CRCParameters::from_name(&parameters.name.to_lowercase()).unwrap());
+
+        for alias in parameters.aliases() {
+            assert_eq!(*parameters, CRCParameters::from_name(alias).unwrap(), "{}", alias);
+        }
+
+        let mut crc = CRC::from_name(parameters.name).unwrap();
+
+        assert_eq!(CRCParameters {
+            name: "", ..**parameters
+        }, crc.parameters());
+
+        crc.digest(b"123456789");
+
+        assert_eq!(parameters.check, crc.get_crc_u128(), "{}", parameters.name);
+    }
+
+    for (function, crc) in CRC::built_in_functions() {
+        assert_eq!(crc.parameters(), CRC::from_name(function).unwrap().parameters(), "{}", function);
+    }
+
+    assert_eq!(120, CRC::built_in_functions().count());
+}
+
+// TODO: Errors
+
+#[test]
+fn try_create_crc() {
+    assert_eq!(CRC::crc32().parameters(), CRC::try_create_crc(0xEDB88320, 32, 0xFFFFFFFF, 0xFFFFFFFF, true).unwrap().parameters());
+    assert_eq!(CRC::crc82darc().parameters(), CRC::try_create_crc_with_parameters(&CRCParameters::CRC_82_DARC).unwrap().parameters());
+
+    assert_eq!(Err(CRCError::InvalidWidth(0, 128)), CRC::try_create_crc(0x07, 0, 0x00, 0x00, false).map(|crc| crc.parameters()));
+    assert_eq!(Err(CRCError::InvalidWidth(129, 128)), CRC::try_create_crc(0x07, 129, 0x00, 0x00, false).map(|crc| crc.parameters()));
+    assert_eq!(Err(CRCError::InvalidWidth(9, 8)), CRCu8::try_create_crc(0x07, 9, 0x00, 0x00, false).map(|crc| crc.parameters()));
+    assert_eq!(Err(CRCError::InvalidWidth(17, 16)), CRCu16::try_create_crc_with_parameters(&CRCParameters::CRC_17_CAN_FD).map(|crc| crc.parameters()));
+
+    assert_eq!(Err(CRCError::PolynomialOutOfRange), CRCu8::try_create_crc(0x23, 5, 0x00, 0x00, false).map(|crc| crc.parameters()));
+    assert_eq!(Err(CRCError::PolynomialOutOfRange), CRC::try_create_crc(0x1_0000_1021, 16, 0x00, 0x00, false).map(|crc| crc.parameters()));
+
+    assert_eq!(Err(CRCError::EvenPolynomial), CRCu16::try_create_crc(0x1020, 16, 0x0000, 0x0000, false).map(|crc| crc.parameters()));
+    assert_eq!(Err(CRCError::EvenPolynomial), CRCu32::try_create_crc(0x7DB88320, 32, 0xFFFFFFFF, 0xFFFFFFFF, true).map(|crc| crc.parameters()));
+    assert!(CRCu32::try_create_crc(0x04C11DB7, 32, 0xFFFFFFFF, 0xFFFFFFFF, true).is_err());
+
+    assert_eq!(Err(CRCError::InitialValueTooWide), CRCu64::try_create_crc_with_reflection(0x0004820009, 40, 1 << 40, 0x00, false, false).map(|crc| crc.parameters()));
+    assert_eq!(Err(CRCError::InitialValueTooWide), CRCu8::try_create_crc(0x03, 3, 0x08, 0x07, false).map(|crc| crc.parameters()));
+    assert_eq!(Err(CRCError::FinalXorTooWide), CRCu128::try_create_crc(0x03, 70, 0x00, 1 << 70, false).map(|crc| crc.parameters()));
+
+    assert_eq!("the length of bits 0 is not in 1..=128", CRCError::InvalidWidth(0, 128).to_string());
+}
+
+#[test]
+#[should_panic(expected = "the length of bits 129 is not in 1..=128")]
+fn create_crc_too_wide() {
+    CRC::create_crc(0x07, 129, 0x00, 0x00, false);
+}
+
+#[test]
+#[should_panic(expected = "the length of bits 129 is not in 1..=128")]
+fn create_crc_with_reflection_too_wide() {
+    assert_eq!(Err(CRCError::InvalidWidth(129, 128)), CRC::try_create_crc_with_reflection(0x07, 129, 0x00, 0x00, false, true).map(|crc| crc.parameters()));
+
+    CRC::create_crc_with_reflection(0x07, 129, 0x00, 0x00, false, true);
+}
+
+#[test]
+#[should_panic(expected = "the length of bits 129 is not in 1..=128")]
+fn create_crc_with_parameters_too_wide() {
+    let parameters = CRCParameters {
+        width: 129, ..CRCParameters::CRC_82_DARC
+    };
+
+    assert_eq!(Err(CRCError::InvalidWidth(129, 128)), CRC::try_create_crc_with_parameters(&parameters).map(|crc| crc.parameters()));
+
+    CRC::create_crc_with_parameters(&parameters);
+}
+
+#[test]
+fn try_build() {
+    assert_eq!(Ok(CRCParameters::CRC_16_MODBUS), CRCParameters::CRC_16_MODBUS.to_builder().name("CRC-16/MODBUS").try_build());
+
+    assert_eq!(Err(CRCError::PolynomialOutOfRange), CRCParametersBuilder::new(8, 0x1234507).try_build());
+    assert_eq!(Err(CRCError::EvenPolynomial), CRCParametersBuilder::new(8, 0x06).try_build());
+    assert_eq!(Err(CRCError::InitialValueTooWide), CRCParametersBuilder::new(16, 0x8005).init(0x1FFFF).try_build());
+    assert_eq!(Err(CRCError::FinalXorTooWide), CRCParametersBuilder::new(16, 0x8005).xorout(0x10000).try_build());
+
+    // `build` discards the bits which are out of the length of bits
+    assert_eq!(CRCParametersBuilder::new(8, 0x07).build(), CRCParametersBuilder::new(8, 0x1234507).build());
+}
+
+#[test]
+fn identify() {
+    let mut crc = CRC::crc16modbus();
+    crc.digest(b"https://magiclen.org");
+    let value = crc.get_crc() as u128;
+
+    let samples: [(&[u8], u128); 2] = [(b"123456789", 0x4B37), (b"https://magiclen.org", value)];
+
+    assert_eq!(vec![CRCMatch {
+        parameters: &CRCParameters::CRC_16_MODBUS, endian: Endian::Big
+    }], CRC::identify(&samples));
+
+    let swapped: Vec<(&[u8], u128)> = samples.iter().map(|(message, value)| (*message, (*value as u16).swap_bytes() as u128)).collect();
+
+    assert_eq!(vec![CRCMatch {
+        parameters: &CRCParameters::CRC_16_MODBUS, endian: Endian::Little
+    }], CRC::identify(&swapped));
+
+    // the check value of CRC-32/ISO-HDLC
+    let found = CRC::identify(&[(b"123456789", 0xCBF43926)]);
+
+    assert_eq!(1, found.len());
+    assert_eq!("CRC-32/ISO-HDLC", found[0].parameters.name);
+    assert_eq!(Endian::Big, found[0].endian);
+
+    // CRC-8/I-432-1 and CRC-8/MAXIM-DOW have the same check value, so more samples are needed to tell them apart
+    assert_eq!(vec![CRCMatch {
+        parameters: &CRCParameters::CRC_8_I_432_1, endian: Endian::Big
+    }, CRCMatch {
+        parameters: &CRCParameters::CRC_8_MAXIM_DOW, endian: Endian::Big
+    }], CRC::identify(&[(b"123456789", 0xA1)]));
+
+    // 0x5555 reads the same in both byte orders
+    let found = CRC::identify(&[(b"33", 0x5555)]);
+
+    assert_eq!(vec![Endian::Big], found.iter().filter(|m| m.parameters.name == "CRC-16/MODBUS").map(|m| m.endian).collect::<Vec<Endian>>());
+
+    assert!(CRC::identify(&[(b"123456789", 0x12345678)]).is_empty());
+    assert!(CRC::identify(&[]).is_empty());
+}
+
+// TODO: Hasher
+
+#[test]
+fn hasher() {
+    let mut crc = CRCu32::crc32c();
+
+    crc.write(b"123456789");
+
+    assert_eq!(0xE3069283, crc.finish());
+
+    let mut crc = CRC::crc64();
+
+    Hasher::write(&mut crc, b"123456789");
+
+    assert_eq!(CRCParameters::CRC_64_WE.check as u64, crc.finish());
+
+    let mut crc = CRC::crc82darc();
+
+    Hasher::write(&mut crc, b"123456789");
+
+    assert_eq!(CRCParameters::CRC_82_DARC.check as u64, crc.finish());
+
+    let build_hasher = CRCBuildHasher::new(CRCu32::crc32c());
+
+    let mut a = build_hasher.build_hasher();
+    let mut b = build_hasher.build_hasher();
+
+    a.write_u32(12345);
+    b.write_u32(12345);
+
+    assert_eq!(a.finish(), b.finish());
+
+    let mut map = HashMap::with_hasher(CRCBuildHasher::new(CRC::crc64xz()));
+
+    map.insert("crc", 32);
+    map.insert("any", 64);
+
+    assert_eq!(Some(&32), map.get("crc"));
+    assert_eq!(Some(&64), map.get("any"));
+}
+
+// TODO: IO
+
+#[cfg(feature = "std")]
+#[test]
+fn io() {
+    use std::io::{self, Read, Write};
+
+    use crc_any::{CRCReader, CRCWriter};
+
+    let mut crc = CRC::crc32();
+
+    io::copy(&mut &b"123456789"[..], &mut crc).unwrap();
+
+    assert_eq!(0xCBF43926, crc.get_crc());
+
+    let mut crc = CRCu16::crc16modbus();
+
+    Write::write_all(&mut crc, b"123456789").unwrap();
+
+    assert_eq!(0x4B37, crc.get_crc());
+
+    let mut reader = CRCReader::new(&b"https://magiclen.org"[..], CRCu32::crc32c());
+    let mut data = Vec::new();
+
+    reader.read_to_end(&mut data).unwrap();
+
+    assert_eq!(&b"https://magiclen.org"[..], &data[..]);
+    assert_eq!(20, reader.length());
+
+    let mut crc = CRCu32::crc32c();
+    crc.digest(b"https://magiclen.org");
+
+    assert_eq!(crc.get_crc(), reader.crc().get_crc());
+
+    let mut writer = CRCWriter::new(Vec::new(), CRC::crc82darc());
+
+    writer.write_all(b"https://").unwrap();
+    writer.write_all(b"magiclen.org").unwrap();
+    writer.flush().unwrap();
+
+    assert_eq!(20, writer.length());
+
+    let (data, mut crc) = writer.into_parts();
+
+    assert_eq!(&b"https://magiclen.org"[..], &data[..]);
+    assert_eq!("0x212405CB5E5829123EB66", &crc.to_string());
+    assert_eq!(vec![0x02, 0x12, 0x40, 0x5C, 0xB5, 0xE5, 0x82, 0x91, 0x23, 0xEB, 0x66], crc.get_crc_vec_be());
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn futures_io() {
+    use futures::executor::block_on;
+    use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+
+    use crc_any::{CRCReader, CRCWriter};
+
+    let mut reader = CRCReader::new(Cursor::new(b"https://magiclen.org"), CRC::crc32());
+    let mut data = Vec::new();
+
+    block_on(reader.read_to_end(&mut data)).unwrap();
+
+    assert_eq!(&b"https://magiclen.org"[..], &data[..]);
+    assert_eq!(20, reader.length());
+    assert_eq!("0x9D8C7472", &reader.crc().to_string());
+
+    let mut writer = CRCWriter::new(Cursor::new(Vec::new()), CRCu16::crc16modbus());
+
+    block_on(writer.write_all(b"1234")).unwrap();
+    block_on(writer.write_all(b"56789")).unwrap();
+    block_on(writer.close()).unwrap();
+
+    assert_eq!(9, writer.length());
+    assert_eq!(0x4B37, writer.crc().get_crc());
+    assert_eq!(&b"123456789"[..], &writer.get_ref().get_ref()[..]);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use crc_any::{CRCReader, CRCWriter};
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+    let mut reader = CRCReader::new(&b"https://magiclen.org"[..], CRC::crc32());
+    let mut data = Vec::new();
+
+    runtime.block_on(reader.read_to_end(&mut data)).unwrap();
+
+    assert_eq!(&b"https://magiclen.org"[..], &data[..]);
+    assert_eq!(20, reader.length());
+    assert_eq!("0x9D8C7472", &reader.crc().to_string());
+
+    let mut writer = CRCWriter::new(Vec::new(), CRCu16::crc16modbus());
+
+    runtime.block_on(writer.write_all(b"1234")).unwrap();
+    runtime.block_on(writer.write_all(b"56789")).unwrap();
+    runtime.block_on(writer.shutdown()).unwrap();
+
+    assert_eq!(9, writer.length());
+    assert_eq!(0x4B37, writer.crc().get_crc());
+    assert_eq!(&b"123456789"[..], &writer.get_ref()[..]);
+}
+
+// TODO: RustCrypto
+
+#[cfg(feature = "digest")]
+#[test]
+fn rustcrypto() {
+    use crc_any::rustcrypto::{CRCDigest, CRC16Modbus, CRC32C, CRC82Darc, CRC8Maxim};
+    use digest::Digest;
+
+    fn digest<D: Digest>(data: &[u8]) -> Vec<u8> {
+        let mut d = D::new();
+
+        d.update(data);
+
+        d.finalize().to_vec()
+    }
+
+    assert_eq!(vec![0xE3, 0x06, 0x92, 0x83], digest::<CRCDigest<CRC32C>>(b"123456789"));
+    assert_eq!(vec![0x4B, 0x37], digest::<CRCDigest<CRC16Modbus>>(b"123456789"));
+    assert_eq!(vec![0xA1], digest::<CRCDigest<CRC8Maxim>>(b"123456789"));
+    assert_eq!(CRC::crc82darc().get_crc_vec_be().len(), <CRCDigest<CRC82Darc> as Digest>::output_size());
+
+    let mut crc = CRC::crc82darc();
+    crc.digest(b"https://magiclen.org");
+    assert_eq!(crc.get_crc_vec_be(), digest::<CRCDigest<CRC82Darc>>(b"https://magiclen.org"));
+
+    let mut d = CRCDigest::<CRC32C>::new();
+
+    Digest::update(&mut d, b"123456789");
+
+    assert_eq!(&[0xE3, 0x06, 0x92, 0x83], &d.finalize_reset()[..]);
+
+    Digest::update(&mut d, b"123456789");
+
+    assert_eq!(&[0xE3, 0x06, 0x92, 0x83], &d.finalize()[..]);
+
+    // the digest holds the CRC type of the algorithm rather than `CRC`
+    let d = CRCDigest::<CRC8Maxim>::new();
+    let crc: &CRCu8 = d.crc();
+
+    assert_eq!(0x00, crc.get_crc());
+    assert_eq!(std::mem::size_of::<CRCu8>(), std::mem::size_of::<CRCDigest<CRC8Maxim>>());
+}
+
+// TODO: Reverse Engineering
+
+fn samples_of(parameters: &CRCParameters, messages: &[&'static [u8]]) -> Vec<(&'static [u8], u128)> {
+    messages
+        .iter()
+        .map(|message| {
+            let mut crc = CRC::create_crc_with_parameters(parameters);
+
+            crc.digest(message);
+
+            (*message, crc.get_crc_u128())
+        })
+        .collect()
+}
+
+#[test]
+fn reverse_engineer() {
+    let messages: [&'static [u8]; 5] = [b"123456789", b"https://magiclen.org", b"987654321", b"https://magiclen.net", b"crc-any"];
+
+    for parameters in CRCParameters::catalogue() {
+        let samples = samples_of(parameters, &messages);
+
+        let found: Vec<CRCParameters> = CRC::reverse_engineer(&samples, Some(parameters.width)).iter().map(|crc| crc.parameters()).collect();
+
+        assert!(found.contains(&CRCParameters {
+            name: "", ..*parameters
+        }), "{} {:?}", parameters.name, found);
+    }
+}
+
+#[test]
+fn reverse_engineer_without_width() {
+    let messages: [&'static [u8]; 4] = [b"123456789", b"987654321", b"https://magiclen.org", b"https://magiclen.net"];
+
+    let samples = samples_of(&CRCParameters::CRC_16_MODBUS, &messages);
+
+    let found: Vec<CRCParameters> = CRC::reverse_engineer(&samples, None).iter().map(|crc| crc.parameters()).collect();
+
+    // the expression has the factor x + 1, so the lowest bit of the initial value cannot be determined by the lengths of the samples
+    assert_eq!(2, found.len());
+    assert!(found.contains(&CRC::crc16modbus().parameters()));
+}
+
+#[test]
+fn reverse_engineer_same_length() {
+    let messages: [&'static [u8]; 3] = [b"123456789", b"987654321", b"abcdefghi"];
+
+    let samples = samples_of(&CRCParameters::CRC_32_ISO_HDLC, &messages);
+
+    let found: Vec<CRCParameters> = CRC::reverse_engineer(&samples, Some(32)).iter().map(|crc| crc.parameters()).collect();
+
+    // the initial value cannot be told from the final xor value
+    assert!(found.contains(&CRC::crc32().parameters()));
+    assert!(found.iter().any(|parameters| parameters.init == 0 && parameters.refin && parameters.refout && parameters.poly == 0x04C11DB7));
+
+    assert!(CRC::reverse_engineer(&samples[..1], Some(32)).is_empty());
+}
+
+// TODO: Frames
+
+#[test]
+fn append_crc() {
+    let mut crc = CRC::crc32();
+
+    crc.digest(b"123456789");
+
+    let mut frame = b"123456789".to_vec();
+
+    crc.append_crc(&mut frame, Endian::Big);
+
+    assert_eq!(b"123456789\xCB\xF4\x39\x26", &frame[..]);
+    assert!(CRC::crc32().verify_frame(&frame, Endian::Big));
+    assert!(!CRC::crc32().verify_frame(&frame, Endian::Little));
+
+    let mut frame = b"123456789".to_vec();
+
+    crc.append_crc(&mut frame, Endian::Little);
+
+    assert_eq!(b"123456789\x26\x39\xF4\xCB", &frame[..]);
+    assert!(CRC::crc32().verify_frame(&frame, Endian::Little));
+
+    frame[3] ^= 0x10;
+
+    assert!(!CRC::crc32().verify_frame(&frame, Endian::Little));
+    assert!(!CRC::crc32().verify_frame(&frame[..3], Endian::Little));
+}
+
+#[test]
+fn write_crc() {
+    let mut crc = CRCu16::crc16ccitt_false();
+
+    crc.digest(b"123456789");
+
+    let mut buffer = [0u8; 4];
+
+    assert_eq!(2, crc.write_crc(&mut buffer, Endian::Big));
+    assert_eq!([0x29, 0xB1, 0x00, 0x00], buffer);
+
+    assert_eq!(2, crc.write_crc(&mut buffer[1..], Endian::Little));
+    assert_eq!([0x29, 0xB1, 0x29, 0x00], buffer);
+
+    let mut crc = CRC::crc12();
+
+    crc.digest(b"123456789");
+
+    assert_eq!(2, crc.write_crc(&mut buffer, Endian::Big));
+    assert_eq!([0x0F, 0x5B], buffer[..2]);
+}
+
+#[test]
+fn check_residue() {
+    for parameters in CRCParameters::catalogue().filter(|parameters| parameters.width % 8 == 0 && parameters.refin == parameters.refout) {
+        let endian = if parameters.refin {
+            Endian::Little
+        } else {
+            Endian::Big
+        };
+
+        let mut crc = CRC::create_crc_with_parameters(parameters);
+
+        crc.digest(b"123456789");
+
+        let mut frame = b"123456789".to_vec();
+
+        crc.append_crc(&mut frame, endian);
+
+        let mut crc = CRC::create_crc_with_parameters(parameters);
+
+        crc.digest(&frame);
+
+        assert!(crc.check_residue(), "{}", parameters.name);
+
+        frame[0] ^= 1;
+
+        let mut crc = CRC::create_crc_with_parameters(parameters);
+
+        crc.digest(&frame);
+
+        assert!(!crc.check_residue(), "{}", parameters.name);
+    }
+}
+
+// TODO: Correction
+
+fn frame_of(parameters: &CRCParameters, message: &[u8], endian: Endian) -> Vec<u8> {
+    let mut crc = CRC::create_crc_with_parameters(parameters);
+
+    crc.digest(message);
+
+    let mut frame = message.to_vec();
+
+    crc.append_crc(&mut frame, endian);
+
+    frame
+}
+
+#[test]
+fn correct_single_bit() {
+    for parameters in CRCParameters::catalogue() {
+        for &endian in [Endian::Big, Endian::Little].iter() {
+            let frame = frame_of(parameters, b"crc-any", endian);
+
+            let corrector = CRCCorrector::new(parameters, 7, endian).unwrap();
+
+            assert_eq!(Ok(Correction::None), corrector.locate(&frame), "{}", parameters.name);
+
+            for i in 0..frame.len() * 8 {
+                let mut received = frame.clone();
+
+                received[i / 8] ^= 1 << (i % 8);
+
+                match corrector.correct(&mut received) {
+                    Ok(correction) => {
+                        assert_eq!(Correction::Single(i), correction, "{}", parameters.name);
+                        assert_eq!(frame, received, "{}", parameters.name);
+                    },
+                    Err(err) => {
+                        // short CRCs cannot tell all bits of the frame apart
+                        assert!(parameters.width < 16, "{}", parameters.name);
+                        assert_eq!(CorrectionError::Uncorrectable, err);
+                        assert_ne!(frame, received);
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn correct_double_bit() {
+    let parameters = CRCParameters::CRC_32_ISO_HDLC;
+
+    let frame = frame_of(&parameters, b"telemetry", Endian::Little);
+
+    let single = CRCCorrector::new(&parameters, 9, Endian::Little).unwrap();
+    let double = single.clone().double_bit(true);
+
+    for i in 0..frame.len() * 8 {
+        for j in (i + 1)..frame.len() * 8 {
+            let mut received = frame.clone();
+
+            received[i / 8] ^= 1 << (i % 8);
+            received[j / 8] ^= 1 << (j % 8);
+
+            assert_eq!(Err(CorrectionError::Uncorrectable), single.correct(&mut received));
+
+            assert_eq!(Ok(Correction::Double(i, j)), double.correct(&mut received));
+            assert_eq!(frame, received);
+        }
+    }
+
+    let mut received = frame.clone();
+
+    received[0] ^= 0x07;
+
+    assert_eq!(Err(CorrectionError::Uncorrectable), double.correct(&mut received));
+
+    assert_eq!(Err(CorrectionError::InvalidLength(12, 13)), double.correct(&mut received[1..]));
+}